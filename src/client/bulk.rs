@@ -0,0 +1,226 @@
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+use futures::stream::{self, StreamExt};
+use reqwest::StatusCode;
+use tokio::time::Instant;
+
+use crate::client::{retry_after, Endpoint, RetryPolicy, UberClient};
+use crate::errors::UberError;
+use crate::models::create_delivery::CreateDeliveryRequest;
+use crate::models::delivery::Delivery;
+
+/// How many create delivery calls a bulk create keeps in flight
+pub const DEFAULT_BULK_CONCURRENCY: usize = 8;
+
+/// Create many deliveries concurrently, built with [`UberClient::create_deliveries_bulk`]
+///
+/// | Setting      | Default                                  | Description |
+/// |--------------|------------------------------------------|-------------|
+/// | concurrency  | 8                                        | Create delivery calls in flight at once. |
+/// | retry_policy | the client's, or `RetryPolicy::default()` | Attempts per item and the backoff between them. |
+///
+/// A `429 customer_limited` pauses the whole batch, not just the request that got it: no item is sent until the
+/// `Retry-After` delay (or the policy backoff) has passed, then the rate limited items are retried.
+/// Other retryable failures (timeouts, 408, 5xx) are only retried for requests with an `idempotency_key`,
+/// or when the policy marks `Endpoint::CreateDelivery` safe, so a retry can't book the same order twice.
+///
+/// Example:
+/// ```no_run
+/// # async fn run(requests: Vec<uber_api::CreateDeliveryRequest>) {
+/// use uber_api::UberClient;
+///
+/// let client = UberClient::new("cus_1234");
+/// let report = client.create_deliveries_bulk("access_token", requests)
+///     .with_concurrency(4)
+///     .run()
+///     .await;
+///
+/// for item in report.failed() {
+///     log::warn!("Order {:?} failed with {:?} after {} retries", item.manifest_reference, item.error_code(), item.retries);
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BulkCreate {
+    client: UberClient,
+    access_token: String,
+    requests: Vec<CreateDeliveryRequest>,
+    concurrency: usize,
+    retry_policy: RetryPolicy,
+}
+
+/// The outcome of one request of a bulk create
+#[derive(Debug)]
+pub struct BulkItemResult {
+    /// Position of the request in the batch
+    pub index: usize,
+    pub manifest_reference: Option<String>,
+    pub result: Result<Delivery, UberError>,
+    pub retries: u32,
+}
+
+/// Per-request results of a bulk create, in the order the requests were given
+#[derive(Debug, Default)]
+pub struct BulkCreateReport {
+    pub items: Vec<BulkItemResult>,
+    /// How many `429` responses paused the batch
+    pub rate_limited: u32,
+}
+
+impl UberClient {
+    /// Create every delivery in `requests`, see [`BulkCreate`]
+    pub fn create_deliveries_bulk<I>(&self, access_token: &str, requests: I) -> BulkCreate
+    where
+        I: IntoIterator,
+        I::Item: Into<CreateDeliveryRequest>,
+    {
+        BulkCreate {
+            client: self.clone(),
+            access_token: access_token.to_string(),
+            requests: requests.into_iter().map(Into::into).collect(),
+            concurrency: DEFAULT_BULK_CONCURRENCY,
+            retry_policy: self.retry_policy().cloned().unwrap_or_default(),
+        }
+    }
+}
+
+impl BulkCreate {
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Send every request, returning once each one was created or gave up
+    pub async fn run(self) -> BulkCreateReport {
+        let throttle = Throttle::default();
+
+        let mut items: Vec<BulkItemResult> = stream::iter(self.requests.iter().enumerate())
+            .map(|(index, request)| self.create_one(index, request, &throttle))
+            .buffer_unordered(self.concurrency)
+            .collect()
+            .await;
+        items.sort_by_key(|item| item.index);
+
+        BulkCreateReport {
+            items,
+            rate_limited: throttle.pauses.load(Ordering::Relaxed),
+        }
+    }
+
+    async fn create_one(&self, index: usize, request: &CreateDeliveryRequest, throttle: &Throttle) -> BulkItemResult {
+        let retry_safe = request.idempotency_key.is_some() || self.retry_policy.retries_endpoint(Endpoint::CreateDelivery);
+        let mut attempt = 1;
+
+        let result = loop {
+            throttle.wait().await;
+            let last_attempt = attempt >= self.retry_policy.max_attempts();
+
+            let res = match self.client.send_create_delivery_raw(&self.access_token, request, false).await {
+                Ok(res) => res,
+                Err(UberError::ReqwestError(err)) if retry_safe && !last_attempt && RetryPolicy::is_retryable_error(&err) => {
+                    log::info!("Bulk create item {} attempt {} failed with {}, retrying", index, attempt, err);
+                    tokio::time::sleep(self.retry_policy.backoff(attempt, None)).await;
+                    attempt += 1;
+                    continue;
+                }
+                Err(err) => break Err(err),
+            };
+
+            if res.status == StatusCode::TOO_MANY_REQUESTS && !last_attempt {
+                let delay = self.retry_policy.backoff(attempt, retry_after(&res.headers));
+                log::info!("Bulk create rate limited on item {}, pausing the batch for {:?}", index, delay);
+                throttle.pause(delay);
+                attempt += 1;
+                continue;
+            }
+            if retry_safe && !last_attempt && RetryPolicy::is_retryable_status(res.status) {
+                log::info!("Bulk create item {} attempt {} failed with {}, retrying", index, attempt, res.status);
+                tokio::time::sleep(self.retry_policy.backoff(attempt, retry_after(&res.headers))).await;
+                attempt += 1;
+                continue;
+            }
+
+            break res.parse_success().map(|response| response.data);
+        };
+
+        BulkItemResult {
+            index,
+            manifest_reference: request.manifest_reference.clone(),
+            result,
+            retries: attempt - 1,
+        }
+    }
+}
+
+impl BulkItemResult {
+    pub fn is_created(&self) -> bool {
+        self.result.is_ok()
+    }
+
+    pub fn delivery_id(&self) -> Option<&str> {
+        self.result.as_ref().ok()?.id.as_deref()
+    }
+
+    /// `UberError::get_code` of the failure, e.g. `customer_limited` or `address_undeliverable`
+    pub fn error_code(&self) -> Option<String> {
+        self.result.as_ref().err().map(UberError::get_code)
+    }
+}
+
+impl BulkCreateReport {
+    pub fn created(&self) -> impl Iterator<Item = &BulkItemResult> {
+        self.items.iter().filter(|item| item.is_created())
+    }
+
+    pub fn failed(&self) -> impl Iterator<Item = &BulkItemResult> {
+        self.items.iter().filter(|item| !item.is_created())
+    }
+
+    pub fn created_count(&self) -> usize {
+        self.created().count()
+    }
+
+    pub fn failed_count(&self) -> usize {
+        self.failed().count()
+    }
+
+    pub fn all_created(&self) -> bool {
+        self.items.iter().all(BulkItemResult::is_created)
+    }
+}
+
+// batch-wide pause, set when any request is rate limited
+#[derive(Default)]
+struct Throttle {
+    paused_until: Mutex<Option<Instant>>,
+    pauses: AtomicU32,
+}
+
+impl Throttle {
+    async fn wait(&self) {
+        // another request may push the pause further out while this one sleeps
+        loop {
+            let paused_until = *self.paused_until.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            match paused_until {
+                Some(until) if until > Instant::now() => tokio::time::sleep_until(until).await,
+                _ => return,
+            }
+        }
+    }
+
+    fn pause(&self, delay: Duration) {
+        let until = Instant::now() + delay;
+        let mut paused_until = self.paused_until.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if paused_until.is_none_or(|current| current < until) {
+            *paused_until = Some(until);
+        }
+        self.pauses.fetch_add(1, Ordering::Relaxed);
+    }
+}
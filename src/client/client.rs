@@ -0,0 +1,466 @@
+use reqwest::{Client, RequestBuilder, Response, Url};
+use reqwest::header::{HeaderValue, CONTENT_TYPE};
+use reqwest::header::AUTHORIZATION;
+use tokio::time::Instant;
+
+use crate::client::{retry_after, ApiResponse, Endpoint, RawResponse, RetryPolicy};
+use crate::errors::UberError;
+use crate::models::{
+    auth::{
+        AuthRequest,
+        AuthResponse
+    },
+    create_quote::{
+        CreateQuoteRequest,
+        CreateQuoteResponse
+    },
+    create_delivery::{
+        CreateDeliveryRequest,
+        CreateDeliveryResponse,
+        convert_status_to_message_create
+    },
+    get_delivery::{
+        GetDeliveryResponse
+    },
+    update_delivery::{
+        UpdateDeliveryRequest,
+        UpdateDeliveryResponse
+    },
+    cancel_delivery::{
+        CancelDeliveryResponse,
+        convert_status_to_message_cancel
+    },
+    list_deliveries::{
+        ListDeliveriesQuery,
+        ListDeliveriesResponse
+    },
+    pod_retrieval::{
+        PODRetrievalRequest,
+        PODRetrievalResponse,
+        PodType,
+        PodWaypoint,
+        ProofOfDelivery
+    },
+};
+
+pub const DEFAULT_API_BASE_URL: &str = "https://api.uber.com";
+pub const DEFAULT_AUTH_BASE_URL: &str = "https://login.uber.com";
+
+/// Reusable client for the Uber Direct API
+///
+/// Holds one pooled `reqwest::Client`, so connections and TLS sessions are reused between calls, along with the customer_id and the base URLs every endpoint is built from.
+/// Cloning is cheap and clones share the same connection pool.
+///
+/// The base URLs default to `https://api.uber.com` and `https://login.uber.com`, and can be pointed at a local stand-in server in tests.
+///
+/// Example:
+/// ```no_run
+/// # async fn run() -> Result<(), uber_api::UberError> {
+/// use uber_api::{AuthRequest, UberClient};
+///
+/// let client = UberClient::new("cus_1234");
+/// let auth_response = client.auth(AuthRequest::new("client_id", "client_secret")).await?.into_data();
+/// let delivery = client.get_delivery(&auth_response.access_token, "del_1234").await?.into_data();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct UberClient {
+    http_client: Client,
+    customer_id: String,
+    api_base_url: String,
+    auth_base_url: String,
+    retry_policy: Option<RetryPolicy>,
+}
+
+impl Default for UberClient {
+    fn default() -> Self {
+        UberClient::new("")
+    }
+}
+
+impl UberClient {
+    pub fn new<T: Into<String>>(customer_id: T) -> Self {
+        UberClient {
+            http_client: Client::new(),
+            customer_id: customer_id.into(),
+            api_base_url: DEFAULT_API_BASE_URL.to_string(),
+            auth_base_url: DEFAULT_AUTH_BASE_URL.to_string(),
+            retry_policy: None,
+        }
+    }
+
+    /// Use an already configured `reqwest::Client` (timeouts, proxies, pool limits) instead of the default one
+    pub fn with_http_client(mut self, http_client: Client) -> Self {
+        self.http_client = http_client;
+        self
+    }
+
+    /// Base URL for the delivery endpoints, e.g. `http://127.0.0.1:8080`. A trailing `/` is ignored.
+    pub fn with_api_base_url<T: Into<String>>(mut self, api_base_url: T) -> Self {
+        self.api_base_url = trim_base_url(api_base_url.into());
+        self
+    }
+
+    /// Base URL for the OAuth token endpoint, e.g. `http://127.0.0.1:8080`. A trailing `/` is ignored.
+    pub fn with_auth_base_url<T: Into<String>>(mut self, auth_base_url: T) -> Self {
+        self.auth_base_url = trim_base_url(auth_base_url.into());
+        self
+    }
+
+    /// Retry failed calls according to `retry_policy`. Without one every call is sent exactly once.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    pub fn customer_id(&self) -> &str {
+        &self.customer_id
+    }
+
+    pub fn api_base_url(&self) -> &str {
+        &self.api_base_url
+    }
+
+    pub fn auth_base_url(&self) -> &str {
+        &self.auth_base_url
+    }
+
+    pub fn retry_policy(&self) -> Option<&RetryPolicy> {
+        self.retry_policy.as_ref()
+    }
+
+    /// Send `request` and read the response body, repeating it on transient failures when the retry policy allows it for `endpoint`
+    async fn execute(&self, endpoint: Endpoint, request: RequestBuilder) -> Result<RawResponse, UberError> {
+        let retry_safe = self.retry_policy
+            .as_ref()
+            .is_some_and(|retry_policy| retry_policy.retries_endpoint(endpoint));
+
+        self.execute_with(endpoint, request, retry_safe).await
+    }
+
+    /// Like `execute`, with the caller deciding whether repeating the request is safe
+    async fn execute_with(&self, endpoint: Endpoint, request: RequestBuilder, retry_safe: bool) -> Result<RawResponse, UberError> {
+        let started = Instant::now();
+        let res = self.send_with_retries(endpoint, request, retry_safe).await?;
+
+        RawResponse::read(res, started).await
+    }
+
+    async fn send_with_retries(&self, endpoint: Endpoint, request: RequestBuilder, retry_safe: bool) -> Result<Response, UberError> {
+        let retry_policy = match &self.retry_policy {
+            Some(retry_policy) if retry_safe => retry_policy,
+            _ => return Ok(request.send().await?),
+        };
+
+        let mut attempt = 1;
+        loop {
+            let attempt_request = request.try_clone()
+                .ok_or_else(|| UberError::InvalidState("request body can't be retried".to_string()))?;
+            let last_attempt = attempt >= retry_policy.max_attempts();
+
+            let delay = match attempt_request.send().await {
+                Ok(res) if !last_attempt && RetryPolicy::is_retryable_status(res.status()) => {
+                    log::info!("{:?} attempt {} failed with {}, retrying", endpoint, attempt, res.status());
+                    retry_policy.backoff(attempt, retry_after(res.headers()))
+                }
+                Err(err) if !last_attempt && RetryPolicy::is_retryable_error(&err) => {
+                    log::info!("{:?} attempt {} failed with {}, retrying", endpoint, attempt, err);
+                    retry_policy.backoff(attempt, None)
+                }
+                result => return Ok(result?),
+            };
+
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    fn deliveries_url(&self) -> String {
+        format!(
+            "{}/v1/customers/{}/deliveries",
+            self.api_base_url,
+            self.customer_id
+        )
+    }
+
+    fn delivery_url(&self, delivery_id: &str) -> String {
+        format!(
+            "{}/{}",
+            self.deliveries_url(),
+            delivery_id
+        )
+    }
+
+    /// Retrieve access token for authenticated user - docs at [`crate::auth`]
+    pub async fn auth(
+        &self,
+        auth_request: AuthRequest
+    ) -> Result<ApiResponse<AuthResponse>, UberError> {
+
+        let url = format!("{}/oauth/v2/token", self.auth_base_url);
+        let content_type = HeaderValue::from_str("application/x-www-form-urlencoded")?;
+        let body = serde_urlencoded::to_string(&auth_request)?;
+
+        let request = self.http_client.post(&url)
+            .header(CONTENT_TYPE, content_type)
+            .body(body);
+
+        self.execute(Endpoint::Auth, request).await?.parse_success()
+    }
+
+    /// Create a quote to check deliverability, validity and cost for delivery between two addresses - docs at [`crate::create_quote`]
+    pub async fn create_quote(
+        &self,
+        access_token: &str,
+        create_quote_request: CreateQuoteRequest,
+    ) -> Result<ApiResponse<CreateQuoteResponse>, UberError> {
+
+        let url = format!(
+            "{}/v1/customers/{}/delivery_quotes",
+            self.api_base_url,
+            self.customer_id
+        );
+        let content_type = HeaderValue::from_str("application/json")?;
+        let authorization = bearer(access_token)?;
+        let body = serde_json::to_string(&create_quote_request)?;
+
+        let request = self.http_client.post(&url)
+            .header(CONTENT_TYPE, content_type)
+            .header(AUTHORIZATION, authorization)
+            .body(body);
+
+        let res = self.execute(Endpoint::CreateQuote, request).await?;
+
+        log::info!("\nSTATUS CODE RES => {}\n", res.status);
+
+        res.parse_success()
+    }
+
+    /// Create a delivery between two addresses - docs at [`crate::create_delivery`]
+    pub async fn create_delivery<T: Into<CreateDeliveryRequest>>(
+        &self,
+        access_token: &str,
+        create_delivery_request: T,
+    ) -> Result<ApiResponse<CreateDeliveryResponse>, UberError> {
+        self.send_create_delivery(access_token, create_delivery_request.into(), false).await
+    }
+
+    /// Send a create delivery request, `retry_safe` allowing retries even if the policy hasn't marked create_delivery safe
+    pub(crate) async fn send_create_delivery(
+        &self,
+        access_token: &str,
+        create_delivery_request: CreateDeliveryRequest,
+        retry_safe: bool,
+    ) -> Result<ApiResponse<CreateDeliveryResponse>, UberError> {
+
+        let retry_safe = retry_safe || self.retry_policy
+            .as_ref()
+            .is_some_and(|retry_policy| retry_policy.retries_endpoint(Endpoint::CreateDelivery));
+        let res = self.send_create_delivery_raw(access_token, &create_delivery_request, retry_safe).await?;
+
+        let error = res.error();
+        let code = error.as_ref().map(UberError::get_code).unwrap_or_default();
+        let status_message = convert_status_to_message_create(res.status, &code);
+
+        log::info!("Status Message => {}", &status_message);
+
+        if let Some(err) = error {
+            return Err(err);
+        }
+
+        res.parse()
+    }
+
+    /// Send a create delivery request and hand back the response as is, only retried by the policy when `retry_safe`
+    pub(crate) async fn send_create_delivery_raw(
+        &self,
+        access_token: &str,
+        create_delivery_request: &CreateDeliveryRequest,
+        retry_safe: bool,
+    ) -> Result<RawResponse, UberError> {
+
+        let url = self.deliveries_url();
+        let content_type = HeaderValue::from_str("application/json")?;
+        let authorization = bearer(access_token)?;
+        let body = serde_json::to_string(create_delivery_request)?;
+
+        log::info!("JSON request body of Create Req for Uber API {}", body);
+
+        let request = self.http_client.post(&url)
+            .header(CONTENT_TYPE, content_type)
+            .header(AUTHORIZATION, authorization)
+            .body(body);
+
+        let res = self.execute_with(Endpoint::CreateDelivery, request, retry_safe).await?;
+
+        log::info!("\nSTATUS CODE RES => {}\n", res.status);
+        log::info!("JSON response body of Create Req for Uber API {}", res.body);
+
+        Ok(res)
+    }
+
+    /// Retrieve the current status of an existing delivery - docs at [`crate::get_delivery`]
+    pub async fn get_delivery(
+        &self,
+        access_token: &str,
+        delivery_id: &str,
+    ) -> Result<ApiResponse<GetDeliveryResponse>, UberError> {
+
+        let url = self.delivery_url(delivery_id);
+        let authorization = bearer(access_token)?;
+
+        let request = self.http_client.get(&url)
+            .header(AUTHORIZATION, authorization);
+
+        self.execute(Endpoint::GetDelivery, request).await?.parse_success()
+    }
+
+    /// Modify an ongoing delivery - docs at [`crate::update_delivery`]
+    pub async fn update_delivery(
+        &self,
+        access_token: &str,
+        delivery_id: &str,
+        update_delivery_request: UpdateDeliveryRequest,
+    ) -> Result<ApiResponse<UpdateDeliveryResponse>, UberError> {
+
+        let url = self.delivery_url(delivery_id);
+        let content_type = HeaderValue::from_str("application/json")?;
+        let authorization = bearer(access_token)?;
+        let body = serde_json::to_string(&update_delivery_request)?;
+
+        let request = self.http_client.post(&url)
+            .header(CONTENT_TYPE, content_type)
+            .header(AUTHORIZATION, authorization)
+            .body(body);
+
+        self.execute(Endpoint::UpdateDelivery, request).await?.parse_success()
+    }
+
+    /// Cancel an ongoing or previously scheduled delivery - docs at [`crate::cancel_delivery`]
+    pub async fn cancel_delivery(
+        &self,
+        access_token: &str,
+        delivery_id: &str,
+    ) -> Result<ApiResponse<CancelDeliveryResponse>, UberError> {
+
+        let url = format!("{}/cancel", self.delivery_url(delivery_id));
+        let authorization = bearer(access_token)?;
+
+        let request = self.http_client.post(&url)
+            .header(AUTHORIZATION, authorization);
+
+        let res = self.execute(Endpoint::CancelDelivery, request).await?;
+
+        log::info!("Status Code => {}", &res.status);
+        log::info!("Response Body: JSON => {}", &res.body);
+
+        let error = res.error();
+        let code = error.as_ref().map(UberError::get_code).unwrap_or_default();
+        let status_message = convert_status_to_message_cancel(res.status, &code);
+
+        log::info!("Status Message => {}", &status_message);
+
+        if let Some(err) = error {
+            return Err(err);
+        }
+
+        res.parse()
+    }
+
+    /// List deliveries for the customer, optionally filtered by delivery state - docs at [`crate::list_deliveries`]
+    pub async fn list_deliveries(
+        &self,
+        access_token: &str,
+        query: &ListDeliveriesQuery,
+    ) -> Result<ApiResponse<ListDeliveriesResponse>, UberError> {
+
+        let url = self.list_deliveries_url(query)?;
+
+        self.list_deliveries_page(access_token, &url).await
+    }
+
+    pub(crate) fn list_deliveries_url(&self, query: &ListDeliveriesQuery) -> Result<String, UberError> {
+        let query_string = query.to_query_string()?;
+
+        if query_string.is_empty() {
+            return Ok(self.deliveries_url());
+        }
+
+        Ok(format!("{}?{}", self.deliveries_url(), query_string))
+    }
+
+    /// Fetch one page of deliveries from a full list URL, e.g. a `next_href` already resolved by `resolve_href`
+    pub(crate) async fn list_deliveries_page(
+        &self,
+        access_token: &str,
+        url: &str,
+    ) -> Result<ApiResponse<ListDeliveriesResponse>, UberError> {
+
+        let content_type = HeaderValue::from_str("application/json")?;
+        let authorization = bearer(access_token)?;
+
+        let request = self.http_client.get(url)
+            .header(CONTENT_TYPE, content_type)
+            .header(AUTHORIZATION, authorization);
+
+        self.execute(Endpoint::ListDeliveries, request).await?.parse_success()
+    }
+
+    /// Resolve a `next_href` against the API base URL
+    ///
+    /// Only the path and query are kept, so the bearer token is always sent to the configured API host, whatever host Uber puts in the link.
+    pub(crate) fn resolve_href(&self, href: &str) -> String {
+        let path_and_query = match Url::parse(href) {
+            Ok(url) => match url.query() {
+                Some(query) => format!("{}?{}", url.path(), query),
+                None => url.path().to_string(),
+            },
+            Err(_) => format!("/{}", href.trim_start_matches('/')),
+        };
+
+        format!("{}{}", self.api_base_url, path_and_query)
+    }
+
+    /// Return a Proof-of-Delivery (P.O.D.) File - docs at [`crate::pod_retrieval`]
+    pub async fn pod_retrieval(
+        &self,
+        access_token: &str,
+        delivery_id: &str,
+        pod_retrieval_request: PODRetrievalRequest,
+    ) -> Result<ApiResponse<PODRetrievalResponse>, UberError> {
+
+        let url = format!("{}/proof-of-delivery", self.delivery_url(delivery_id));
+        let content_type = HeaderValue::from_str("application/json")?;
+        let authorization = bearer(access_token)?;
+        let body = serde_json::to_string(&pod_retrieval_request)?;
+
+        let request = self.http_client.post(&url)
+            .header(CONTENT_TYPE, content_type)
+            .header(AUTHORIZATION, authorization)
+            .body(body);
+
+        self.execute(Endpoint::PodRetrieval, request).await?.parse_success()
+    }
+
+    /// Download and decode the proof of delivery image captured at `waypoint`
+    pub async fn proof_of_delivery(
+        &self,
+        access_token: &str,
+        delivery_id: &str,
+        waypoint: PodWaypoint,
+        pod_type: PodType,
+    ) -> Result<ProofOfDelivery, UberError> {
+        let request = PODRetrievalRequest::new(waypoint, pod_type);
+        self.pod_retrieval(access_token, delivery_id, request).await?.data.decode()
+    }
+}
+
+fn bearer(access_token: &str) -> Result<HeaderValue, UberError> {
+    let auth_header = format!("Bearer {}", access_token);
+    Ok(HeaderValue::from_str(&auth_header)?)
+}
+
+fn trim_base_url(base_url: String) -> String {
+    base_url.trim_end_matches('/').to_string()
+}
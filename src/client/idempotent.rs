@@ -0,0 +1,80 @@
+use crate::client::UberClient;
+use crate::errors::{UberError, UnwrapRequestField};
+use crate::models::{
+    create_delivery::{
+        CreateDeliveryRequest
+    },
+    delivery::{
+        Delivery
+    },
+};
+
+/// Result of [`UberClient::create_delivery_idempotent`]
+#[derive(Debug)]
+pub enum CreateDeliveryOutcome {
+    /// A new delivery was created
+    Created(Delivery),
+    /// Uber answered `duplicate_delivery`, this is the delivery that already existed for the same key
+    Existing(Delivery),
+}
+
+impl CreateDeliveryOutcome {
+    pub fn delivery(&self) -> &Delivery {
+        match self {
+            Self::Created(delivery) | Self::Existing(delivery) => delivery,
+        }
+    }
+
+    pub fn into_delivery(self) -> Delivery {
+        match self {
+            Self::Created(delivery) | Self::Existing(delivery) => delivery,
+        }
+    }
+
+    pub fn delivery_id(&self) -> Option<&str> {
+        self.delivery().id.as_deref()
+    }
+
+    pub fn is_existing(&self) -> bool {
+        matches!(self, Self::Existing(_))
+    }
+}
+
+impl UberClient {
+    /// Create a delivery at most once for its `manifest_reference` (or `external_id`)
+    ///
+    /// The key is also used as the `idempotency_key` when none is set, which makes the call safe to retry under the client's `RetryPolicy`.
+    /// If Uber answers `duplicate_delivery`, the existing delivery is looked up from the pointer in the error and returned as `CreateDeliveryOutcome::Existing`
+    /// instead of an error, so a repeated call never dispatches a second courier.
+    ///
+    /// Returns `UberError::BadInput` when the request has neither a manifest_reference nor an external_id.
+    pub async fn create_delivery_idempotent<T: Into<CreateDeliveryRequest>>(
+        &self,
+        access_token: &str,
+        create_delivery_request: T,
+    ) -> Result<CreateDeliveryOutcome, UberError> {
+
+        let mut create_delivery_request = create_delivery_request.into();
+        let key = create_delivery_request.manifest_reference
+            .clone()
+            .or_else(|| create_delivery_request.external_id.clone())
+            .unwrap_request_field("manifest_reference or external_id")?;
+
+        create_delivery_request.idempotency_key.get_or_insert(key);
+
+        match self.send_create_delivery(access_token, create_delivery_request, true).await {
+            Ok(response) => Ok(CreateDeliveryOutcome::Created(response.data)),
+            Err(err) => {
+                let delivery_id = match err.duplicate_delivery_id() {
+                    Some(delivery_id) => delivery_id,
+                    None => return Err(err),
+                };
+
+                log::info!("Delivery already exists => {}, fetching it instead", delivery_id);
+
+                let response = self.get_delivery(access_token, &delivery_id).await?;
+                Ok(CreateDeliveryOutcome::Existing(response.data))
+            }
+        }
+    }
+}
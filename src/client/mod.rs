@@ -0,0 +1,20 @@
+mod bulk;
+#[allow(clippy::module_inception)]
+mod client;
+mod idempotent;
+mod paginator;
+mod quoted;
+mod response;
+mod retry;
+mod token_provider;
+mod tracker;
+
+pub use bulk::*;
+pub use client::*;
+pub use idempotent::*;
+pub use paginator::*;
+pub use quoted::*;
+pub use response::*;
+pub use retry::*;
+pub use token_provider::*;
+pub use tracker::*;
\ No newline at end of file
@@ -0,0 +1,174 @@
+use std::collections::VecDeque;
+
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
+use tokio::task::JoinHandle;
+
+use crate::client::{ApiResponse, UberClient};
+use crate::errors::UberError;
+use crate::models::delivery::Delivery;
+use crate::models::list_deliveries::{ListDeliveriesQuery, ListDeliveriesResponse};
+
+type PageResult = Result<ApiResponse<ListDeliveriesResponse>, UberError>;
+
+/// Walks every page of list_deliveries by following `next_href`, built with [`UberClient::list_deliveries_paginated`]
+///
+/// | Setting   | Default | Description |
+/// |-----------|---------|-------------|
+/// | page_size | Uber's  | `limit` sent with the first request, overriding the query's. Later pages keep whatever `next_href` asks for. |
+/// | max_items | none    | Stop after this many deliveries, without requesting further pages. |
+/// | prefetch  | true    | Request the next page in the background while the current one is being consumed. |
+///
+/// Example:
+/// ```no_run
+/// # async fn run() -> Result<(), uber_api::UberError> {
+/// use futures::TryStreamExt;
+/// use uber_api::{DeliveryFilter, ListDeliveriesQuery, UberClient};
+///
+/// let client = UberClient::new("cus_1234");
+/// let query = ListDeliveriesQuery::new().with_filter(DeliveryFilter::Delivered);
+/// let mut deliveries = client.list_deliveries_paginated("access_token", query)
+///     .with_page_size(100)
+///     .into_stream();
+///
+/// while let Some(delivery) = deliveries.try_next().await? {
+///     log::info!("{:?}", delivery.id);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct DeliveryPaginator {
+    client: UberClient,
+    access_token: String,
+    query: ListDeliveriesQuery,
+    max_items: Option<usize>,
+    prefetch: bool,
+}
+
+impl UberClient {
+    /// Page through all deliveries for the customer matching `query`, starting at its offset
+    pub fn list_deliveries_paginated(&self, access_token: &str, query: ListDeliveriesQuery) -> DeliveryPaginator {
+        DeliveryPaginator {
+            client: self.clone(),
+            access_token: access_token.to_string(),
+            query,
+            max_items: None,
+            prefetch: true,
+        }
+    }
+}
+
+impl DeliveryPaginator {
+    pub fn with_page_size(mut self, page_size: u32) -> Self {
+        self.query.limit = Some(page_size);
+        self
+    }
+
+    pub fn with_max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    pub fn with_prefetch(mut self, prefetch: bool) -> Self {
+        self.prefetch = prefetch;
+        self
+    }
+
+    /// Stream the deliveries one at a time. The stream ends after the first error, including an invalid query.
+    pub fn into_stream(self) -> BoxStream<'static, Result<Delivery, UberError>> {
+        let first_page = match self.client.list_deliveries_url(&self.query) {
+            Ok(url) => url,
+            Err(err) => return stream::once(async { Err(err) }).boxed(),
+        };
+
+        let state = PageState {
+            client: self.client,
+            access_token: self.access_token,
+            next_url: Some(first_page),
+            buffered: VecDeque::new(),
+            in_flight: None,
+            remaining: self.max_items,
+            prefetch: self.prefetch,
+        };
+
+        stream::unfold(state, PageState::next_delivery).boxed()
+    }
+
+    /// Fetch every page and return all deliveries, up to `max_items`
+    pub async fn collect_all(self) -> Result<Vec<Delivery>, UberError> {
+        self.into_stream().try_collect().await
+    }
+}
+
+struct PageState {
+    client: UberClient,
+    access_token: String,
+    next_url: Option<String>,
+    buffered: VecDeque<Delivery>,
+    in_flight: Option<JoinHandle<PageResult>>,
+    remaining: Option<usize>,
+    prefetch: bool,
+}
+
+impl PageState {
+    async fn next_delivery(mut self) -> Option<(Result<Delivery, UberError>, Self)> {
+        loop {
+            if self.remaining == Some(0) {
+                return None;
+            }
+
+            if let Some(delivery) = self.buffered.pop_front() {
+                if let Some(remaining) = self.remaining.as_mut() {
+                    *remaining -= 1;
+                }
+                return Some((Ok(delivery), self));
+            }
+
+            let page = match self.in_flight.take() {
+                Some(handle) => handle.await
+                    .unwrap_or_else(|err| Err(UberError::InvalidState(format!("prefetching deliveries failed: {}", err)))),
+                None => match self.next_url.take() {
+                    Some(url) => fetch_page(self.client.clone(), self.access_token.clone(), url).await,
+                    None => return None,
+                },
+            };
+
+            let page = match page {
+                Ok(page) => page.data,
+                Err(err) => {
+                    self.next_url = None;
+                    return Some((Err(err), self));
+                }
+            };
+
+            self.buffered.extend(page.data.unwrap_or_default());
+
+            // an empty page with a next_href would otherwise be followed forever
+            self.next_url = match page.next_href {
+                Some(href) if !self.buffered.is_empty() => Some(self.client.resolve_href(&href)),
+                _ => None,
+            };
+
+            let needs_more = self.remaining.is_none_or(|remaining| remaining > self.buffered.len());
+            if self.prefetch && needs_more {
+                if let Some(url) = self.next_url.take() {
+                    let fetch = fetch_page(self.client.clone(), self.access_token.clone(), url);
+                    self.in_flight = Some(tokio::spawn(fetch));
+                }
+            }
+        }
+    }
+}
+
+impl Drop for PageState {
+    fn drop(&mut self) {
+        if let Some(handle) = self.in_flight.take() {
+            handle.abort();
+        }
+    }
+}
+
+async fn fetch_page(client: UberClient, access_token: String, url: String) -> PageResult {
+    log::info!("Fetching deliveries page => {}", url);
+    client.list_deliveries_page(&access_token, &url).await
+}
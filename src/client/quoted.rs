@@ -0,0 +1,230 @@
+use std::time::Duration;
+
+use futures::TryStreamExt;
+
+use crate::client::UberClient;
+use crate::errors::{UberError, UberErrorCode, UnwrapRequestField};
+use crate::models::create_delivery::CreateDeliveryRequest;
+use crate::models::create_quote::{CreateQuoteRequest, CreateQuoteResponse};
+use crate::models::delivery::Delivery;
+use crate::models::general::Timestamp;
+use crate::models::list_deliveries::ListDeliveriesQuery;
+use crate::models::money::Money;
+
+/// A quote must still be valid this long after it's checked, to leave time for the create delivery call
+pub const DEFAULT_QUOTE_EXPIRY_MARGIN: Duration = Duration::from_secs(30);
+/// How many fresh quotes are requested after `expired_quote` / `used_quote` before giving up
+pub const DEFAULT_MAX_REQUOTES: u32 = 2;
+/// How many of the latest deliveries are searched for one already booked with a used quote
+pub const DEFAULT_USED_QUOTE_LOOKUP: usize = 50;
+
+/// Quote a delivery, then book it for exactly the quoted fee, built with [`UberClient::quoted_delivery`]
+///
+/// | Setting           | Default | Description |
+/// |-------------------|---------|-------------|
+/// | expiry_margin     | 30s     | Quotes expiring within this margin are treated as expired. |
+/// | max_requotes      | 2       | Fresh quotes requested when the quote expired or was already used. A fresh quote must have the same fee. |
+/// | used_quote_lookup | 50      | Latest deliveries searched for one booked with a used quote before re-quoting. |
+///
+/// A `used_quote` answer can mean an earlier attempt at this create delivery call reached Uber, e.g. a retried request.
+/// The delivery booked with the quote is then looked up and returned with `existing` set, it's only re-quoted when none is found.
+///
+/// Errors:
+/// - `UberError::Validation` with `expired_quote` when no quote stays valid long enough
+/// - `UberError::QuoteMismatch` when Uber answers `mismatched_price_quote`, a fresh quote has a different fee,
+///   or the delivery was created with a fee other than the quoted one (its id is in `delivery_id`, so it can be canceled)
+///
+/// Example:
+/// ```no_run
+/// # async fn run(request: uber_api::CreateDeliveryRequest) -> Result<(), uber_api::UberError> {
+/// use uber_api::UberClient;
+///
+/// let client = UberClient::new("cus_1234");
+/// let flow = client.quoted_delivery("access_token", request);
+///
+/// let quote = flow.quote().await?;
+/// log::info!("Delivery fee {:?}", quote.fee.as_ref().map(|fee| fee.format("en-US")));
+///
+/// let booking = flow.book(quote).await?;
+/// log::info!("Booked {:?}", booking.delivery.id);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct QuotedDelivery {
+    client: UberClient,
+    access_token: String,
+    request: CreateDeliveryRequest,
+    expiry_margin: Duration,
+    max_requotes: u32,
+    used_quote_lookup: usize,
+}
+
+/// A delivery booked by [`QuotedDelivery`], with the quote it was booked with
+#[derive(Debug)]
+pub struct QuotedBooking {
+    /// A fresh quote with the same fee when `requotes` > 0
+    pub quote: CreateQuoteResponse,
+    pub delivery: Delivery,
+    pub requotes: u32,
+    /// The delivery was already booked with the quote, found after Uber answered `used_quote`
+    pub existing: bool,
+}
+
+impl UberClient {
+    /// Quote-then-book flow for `create_delivery_request`, see [`QuotedDelivery`]. Any `quote_id` already on the request is replaced.
+    pub fn quoted_delivery<T: Into<CreateDeliveryRequest>>(&self, access_token: &str, create_delivery_request: T) -> QuotedDelivery {
+        QuotedDelivery {
+            client: self.clone(),
+            access_token: access_token.to_string(),
+            request: create_delivery_request.into(),
+            expiry_margin: DEFAULT_QUOTE_EXPIRY_MARGIN,
+            max_requotes: DEFAULT_MAX_REQUOTES,
+            used_quote_lookup: DEFAULT_USED_QUOTE_LOOKUP,
+        }
+    }
+}
+
+impl QuotedDelivery {
+    pub fn with_expiry_margin(mut self, expiry_margin: Duration) -> Self {
+        self.expiry_margin = expiry_margin;
+        self
+    }
+
+    pub fn with_max_requotes(mut self, max_requotes: u32) -> Self {
+        self.max_requotes = max_requotes;
+        self
+    }
+
+    pub fn with_used_quote_lookup(mut self, used_quote_lookup: usize) -> Self {
+        self.used_quote_lookup = used_quote_lookup;
+        self
+    }
+
+    /// Request a quote for the delivery, failing with `expired_quote` if it doesn't outlast the expiry margin
+    pub async fn quote(&self) -> Result<CreateQuoteResponse, UberError> {
+        let quote_request = CreateQuoteRequest::from(&self.request);
+        let quote = self.client.create_quote(&self.access_token, quote_request).await?.data;
+
+        let quote_id = quote.id.clone().unwrap_request_field("quote.id")?;
+        if self.is_expired(&quote) {
+            return Err(expired(&quote_id));
+        }
+
+        Ok(quote)
+    }
+
+    /// Book the delivery with `quote`, re-quoting when it expired or was used by another delivery, and check the fee charged is the quoted one
+    pub async fn book(&self, quote: CreateQuoteResponse) -> Result<QuotedBooking, UberError> {
+        let quoted_fee = quote.fee.clone();
+        let mut current = quote;
+        let mut requotes = 0;
+
+        loop {
+            let quote_id = current.id.clone().unwrap_request_field("quote.id")?;
+
+            let outcome = if self.is_expired(&current) {
+                Err(expired(&quote_id))
+            } else {
+                self.create_delivery(&quote_id).await
+            };
+
+            match outcome {
+                Ok((delivery, existing)) => {
+                    check_fee(&quote_id, quoted_fee.as_ref(), &delivery)?;
+                    return Ok(QuotedBooking { quote: current, delivery, requotes, existing });
+                }
+                Err(err) if is_stale_quote(&err) && requotes < self.max_requotes => {
+                    log::info!("Quote {} can't be used ({}), requesting a new one", quote_id, err);
+                    requotes += 1;
+                    current = self.quote().await?;
+                    if current.fee != quoted_fee {
+                        return Err(UberError::QuoteMismatch {
+                            quote_id,
+                            message: format!("new quote fee {} differs from the quoted {}", describe(current.fee.as_ref()), describe(quoted_fee.as_ref())),
+                            delivery_id: None,
+                        });
+                    }
+                }
+                Err(err) if err.error_code() == Some(&UberErrorCode::MismatchedPriceQuote) => {
+                    return Err(UberError::QuoteMismatch {
+                        quote_id,
+                        message: format!("Uber rejected the quote for this delivery: {}", err),
+                        delivery_id: None,
+                    });
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// [`QuotedDelivery::quote`] then [`QuotedDelivery::book`]
+    pub async fn quote_and_book(&self) -> Result<QuotedBooking, UberError> {
+        let quote = self.quote().await?;
+        self.book(quote).await
+    }
+
+    // the delivery booked with `quote_id`, and whether it was booked before this call
+    async fn create_delivery(&self, quote_id: &str) -> Result<(Delivery, bool), UberError> {
+        let mut request = self.request.clone();
+        request.quote_id = Some(quote_id.to_string());
+
+        match self.client.create_delivery(&self.access_token, request).await {
+            Ok(response) => Ok((response.data, false)),
+            Err(err) if err.error_code() == Some(&UberErrorCode::UsedQuote) => match self.delivery_booked_with(quote_id).await? {
+                Some(delivery) => {
+                    log::info!("Quote {} already booked delivery {:?}", quote_id, delivery.id);
+                    Ok((delivery, true))
+                }
+                None => Err(err),
+            },
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn delivery_booked_with(&self, quote_id: &str) -> Result<Option<Delivery>, UberError> {
+        let mut deliveries = self.client
+            .list_deliveries_paginated(&self.access_token, ListDeliveriesQuery::new())
+            .with_max_items(self.used_quote_lookup)
+            .into_stream();
+
+        while let Some(delivery) = deliveries.try_next().await? {
+            if delivery.quote_id.as_deref() == Some(quote_id) {
+                return Ok(Some(delivery));
+            }
+        }
+        Ok(None)
+    }
+
+    fn is_expired(&self, quote: &CreateQuoteResponse) -> bool {
+        let margin = chrono::Duration::from_std(self.expiry_margin).unwrap_or(chrono::Duration::MAX);
+        quote.expires
+            .is_some_and(|expires| expires.as_utc().signed_duration_since(Timestamp::now().as_utc()) <= margin)
+    }
+}
+
+fn is_stale_quote(err: &UberError) -> bool {
+    matches!(err.error_code(), Some(UberErrorCode::ExpiredQuote | UberErrorCode::UsedQuote))
+}
+
+fn expired(quote_id: &str) -> UberError {
+    UberError::Validation {
+        code: UberErrorCode::ExpiredQuote,
+        message: format!("quote {} expires too soon to book", quote_id),
+    }
+}
+
+fn check_fee(quote_id: &str, quoted_fee: Option<&Money>, delivery: &Delivery) -> Result<(), UberError> {
+    match (quoted_fee, delivery.fee.as_ref()) {
+        (Some(quoted), Some(charged)) if quoted != charged => Err(UberError::QuoteMismatch {
+            quote_id: quote_id.to_string(),
+            message: format!("delivery was booked for {} instead of the quoted {}", charged, quoted),
+            delivery_id: delivery.id.clone(),
+        }),
+        _ => Ok(()),
+    }
+}
+
+fn describe(fee: Option<&Money>) -> String {
+    fee.map_or_else(|| "no fee".to_string(), Money::to_string)
+}
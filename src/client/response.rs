@@ -0,0 +1,123 @@
+use std::time::Duration;
+
+use reqwest::{Response, StatusCode};
+use reqwest::header::HeaderMap;
+use serde::de::DeserializeOwned;
+use tokio::time::Instant;
+
+use crate::errors::UberError;
+
+/// Headers Uber uses to identify a request, checked in this order
+const REQUEST_ID_HEADERS: [&str; 2] = ["x-uber-request-id", "x-request-id"];
+
+/// What every `UberClient` endpoint returns: the parsed body along with the raw HTTP details of the call
+///
+/// | Field      | Description |
+/// |------------|-------------|
+/// | data       | Response body parsed into the endpoint's response struct. |
+/// | raw_body   | Response body exactly as Uber sent it. |
+/// | status     | HTTP status of the response. |
+/// | headers    | Response headers. |
+/// | request_id | Uber's id for the request (`X-Uber-Request-Id`, or `X-Request-Id`), quote it when contacting Uber support. |
+/// | latency    | Time from sending the request until the body was read, including any retries. |
+///
+/// Example:
+/// ```no_run
+/// # async fn run() -> Result<(), uber_api::UberError> {
+/// use uber_api::UberClient;
+///
+/// let client = UberClient::new("cus_1234");
+/// let response = client.get_delivery("access_token", "del_1234").await?;
+/// log::info!("{} {:?} in {:?}", response.status, response.request_id, response.latency);
+///
+/// let delivery = response.into_data();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ApiResponse<T> {
+    pub data: T,
+    pub raw_body: String,
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub request_id: Option<String>,
+    pub latency: Duration,
+}
+
+impl<T> ApiResponse<T> {
+    pub fn into_data(self) -> T {
+        self.data
+    }
+
+    /// Replace the parsed body, keeping the raw body, status, headers, request id and latency
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> ApiResponse<U> {
+        ApiResponse {
+            data: f(self.data),
+            raw_body: self.raw_body,
+            status: self.status,
+            headers: self.headers,
+            request_id: self.request_id,
+            latency: self.latency,
+        }
+    }
+}
+
+/// A response whose body has been read but not parsed yet
+pub(crate) struct RawResponse {
+    pub(crate) status: StatusCode,
+    pub(crate) headers: HeaderMap,
+    pub(crate) body: String,
+    pub(crate) latency: Duration,
+}
+
+impl RawResponse {
+    pub(crate) async fn read(res: Response, started: Instant) -> Result<Self, UberError> {
+        let status = res.status();
+        let headers = res.headers().clone();
+        let body = res.text().await?;
+
+        Ok(RawResponse {
+            status,
+            headers,
+            body,
+            latency: started.elapsed(),
+        })
+    }
+
+    /// `UberError::Api` for non-2xx responses, so they aren't parsed as the success body
+    pub(crate) fn error(&self) -> Option<UberError> {
+        if self.status.is_success() {
+            return None;
+        }
+
+        let err = UberError::from_response(self.status, &self.body);
+        log::info!("Uber API error => {}", err);
+
+        Some(err)
+    }
+
+    pub(crate) fn parse<T: DeserializeOwned>(self) -> Result<ApiResponse<T>, UberError> {
+        let data = serde_json::from_str(&self.body)?;
+        let request_id = REQUEST_ID_HEADERS.iter()
+            .find_map(|name| self.headers.get(*name))
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+
+        Ok(ApiResponse {
+            data,
+            raw_body: self.body,
+            status: self.status,
+            headers: self.headers,
+            request_id,
+            latency: self.latency,
+        })
+    }
+
+    /// Parse a 2xx body, turning anything else into `UberError::Api`
+    pub(crate) fn parse_success<T: DeserializeOwned>(self) -> Result<ApiResponse<T>, UberError> {
+        match self.error() {
+            Some(err) => Err(err),
+            None => self.parse(),
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use reqwest::StatusCode;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+
+/// The Direct endpoints the client calls, used to decide which calls a `RetryPolicy` may send more than once
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endpoint {
+    Auth,
+    CreateQuote,
+    CreateDelivery,
+    GetDelivery,
+    UpdateDelivery,
+    CancelDelivery,
+    ListDeliveries,
+    PodRetrieval,
+}
+
+impl Endpoint {
+    /// Calls that don't change anything on Uber's side, so repeating them is always safe
+    pub fn is_idempotent(&self) -> bool {
+        matches!(
+            self,
+            Self::Auth
+                | Self::GetDelivery
+                | Self::ListDeliveries
+                | Self::PodRetrieval
+        )
+    }
+}
+
+/// Opt-in retry policy for `UberClient`
+///
+/// Retries timeouts, connection failures and `408 request_timeout`, `429 customer_limited` and 5xx responses with exponential backoff and full jitter.
+/// A `Retry-After` header on the response takes precedence over the computed backoff, clamped to `max_backoff` (30s by default)
+/// so a server or proxy asking for hours, or a date far in the future, can't stall the client.
+///
+/// Idempotent endpoints (auth, get_delivery, list_deliveries, pod_retrieval) are retried by default. Mutating endpoints are only retried once marked safe with `mark_safe`,
+/// e.g. create_delivery when every request carries an idempotency_key.
+///
+/// Example:
+/// ```
+/// use std::time::Duration;
+/// use uber_api::{Endpoint, RetryPolicy, UberClient};
+///
+/// let client = UberClient::new("cus_1234").with_retry_policy(
+///     RetryPolicy::new(4)
+///         .with_initial_backoff(Duration::from_millis(250))
+///         .mark_safe(Endpoint::CreateQuote)
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    safe_mutations: HashSet<Endpoint>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::new(3)
+    }
+}
+
+impl RetryPolicy {
+    /// `max_attempts` counts the first request, so `RetryPolicy::new(1)` never retries
+    pub fn new(max_attempts: u32) -> Self {
+        RetryPolicy {
+            max_attempts: max_attempts.max(1),
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            safe_mutations: HashSet::new(),
+        }
+    }
+
+    pub fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Allow retrying a mutating endpoint, only do this when repeating the call can't create duplicates
+    pub fn mark_safe(mut self, endpoint: Endpoint) -> Self {
+        self.safe_mutations.insert(endpoint);
+        self
+    }
+
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    pub fn retries_endpoint(&self, endpoint: Endpoint) -> bool {
+        endpoint.is_idempotent() || self.safe_mutations.contains(&endpoint)
+    }
+
+    pub fn is_retryable_status(status: StatusCode) -> bool {
+        status == StatusCode::REQUEST_TIMEOUT
+            || status == StatusCode::TOO_MANY_REQUESTS
+            || status.is_server_error()
+    }
+
+    pub fn is_retryable_error(err: &reqwest::Error) -> bool {
+        err.is_timeout() || err.is_connect()
+    }
+
+    /// Delay before the next attempt, `attempt` being the 1-based attempt that just failed. `retry_after` is used as is up to `max_backoff`.
+    pub fn backoff(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        if let Some(retry_after) = retry_after {
+            return retry_after.min(self.max_backoff);
+        }
+
+        let exponent = attempt.saturating_sub(1).min(16);
+        let ceiling = self.initial_backoff
+            .saturating_mul(1 << exponent)
+            .min(self.max_backoff);
+
+        // full jitter: anywhere between zero and the exponential ceiling
+        let ceiling_millis = ceiling.as_millis() as u64;
+        if ceiling_millis == 0 {
+            return ceiling;
+        }
+        Duration::from_millis(rand::thread_rng().gen_range(0..=ceiling_millis))
+    }
+}
+
+/// Parse a `Retry-After` header given either as delay-seconds or as an HTTP date
+pub fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let retry_at = DateTime::parse_from_rfc2822(value).ok()?;
+    let delay = retry_at.with_timezone(&Utc) - Utc::now();
+    Some(delay.to_std().unwrap_or_default())
+}
@@ -0,0 +1,127 @@
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+use crate::client::UberClient;
+use crate::errors::UberError;
+use crate::models::auth::AuthRequest;
+
+/// How long before `expires_in` runs out a cached token is considered stale and refreshed
+pub const DEFAULT_REFRESH_MARGIN: Duration = Duration::from_secs(5 * 60);
+
+/// Caches the OAuth access token for an `AuthRequest` and refreshes it shortly before it expires
+///
+/// Clones share the same cache. Concurrent callers that find the token missing or stale wait on a single refresh rather than each calling `auth()`.
+///
+/// Example:
+/// ```no_run
+/// # async fn run() -> Result<(), uber_api::UberError> {
+/// use uber_api::{AuthRequest, TokenProvider, UberClient};
+///
+/// let client = UberClient::new("cus_1234");
+/// let tokens = TokenProvider::new(client.clone(), AuthRequest::new("client_id", "client_secret"));
+///
+/// // re-authenticates once and retries if Uber answers with a 401
+/// let delivery = tokens.with_token(|access_token| {
+///     let client = client.clone();
+///     async move { client.get_delivery(&access_token, "del_1234").await }
+/// }).await?.into_data();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct TokenProvider {
+    client: UberClient,
+    auth_request: AuthRequest,
+    refresh_margin: Duration,
+    cached_token: Arc<Mutex<Option<CachedToken>>>,
+}
+
+#[derive(Debug)]
+struct CachedToken {
+    access_token: String,
+    refresh_at: Instant,
+}
+
+impl TokenProvider {
+    pub fn new(client: UberClient, auth_request: AuthRequest) -> Self {
+        TokenProvider {
+            client,
+            auth_request,
+            refresh_margin: DEFAULT_REFRESH_MARGIN,
+            cached_token: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Refresh the token this long before it expires instead of [`DEFAULT_REFRESH_MARGIN`].
+    /// Tokens living less than twice the margin are refreshed halfway through their lifetime instead.
+    pub fn with_refresh_margin(mut self, refresh_margin: Duration) -> Self {
+        self.refresh_margin = refresh_margin;
+        self
+    }
+
+    pub fn client(&self) -> &UberClient {
+        &self.client
+    }
+
+    /// Return the cached access token, authenticating first if there is none or it is about to expire
+    pub async fn access_token(&self) -> Result<String, UberError> {
+        // the lock is held across the auth call so concurrent refreshes collapse into one request
+        let mut cached_token = self.cached_token.lock().await;
+
+        if let Some(token) = cached_token.as_ref() {
+            if Instant::now() < token.refresh_at {
+                return Ok(token.access_token.clone());
+            }
+        }
+
+        log::info!("Refreshing Uber access token");
+
+        let auth_response = self.client.auth(self.auth_request.clone()).await?.data;
+        let expires_in = Duration::from_secs(auth_response.expires_in.max(0) as u64);
+        // a short-lived (e.g. sandbox) token within the margin from the start would otherwise be refreshed on every call
+        let refresh_margin = self.refresh_margin.min(expires_in / 2);
+
+        *cached_token = Some(CachedToken {
+            access_token: auth_response.access_token.clone(),
+            refresh_at: Instant::now() + expires_in - refresh_margin,
+        });
+
+        Ok(auth_response.access_token)
+    }
+
+    /// Drop the cached token so the next call to `access_token` re-authenticates
+    pub async fn invalidate(&self) {
+        *self.cached_token.lock().await = None;
+    }
+
+    /// Run `call` with a valid access token. If it fails as unauthorized, re-authenticate once and retry it with the new token.
+    pub async fn with_token<F, Fut, T>(&self, call: F) -> Result<T, UberError>
+    where
+        F: Fn(String) -> Fut,
+        Fut: Future<Output = Result<T, UberError>>,
+    {
+        let access_token = self.access_token().await?;
+
+        match call(access_token.clone()).await {
+            Err(err) if err.is_unauthorized() => {
+                log::info!("Uber rejected the access token, re-authenticating - {}", err);
+                self.invalidate_if_current(&access_token).await;
+                let access_token = self.access_token().await?;
+                call(access_token).await
+            }
+            result => result,
+        }
+    }
+
+    /// Only drop the cached token if it is the one that was rejected, so concurrent 401s don't trigger a refresh each
+    async fn invalidate_if_current(&self, rejected_token: &str) {
+        let mut cached_token = self.cached_token.lock().await;
+        if cached_token.as_ref().map(|token| token.access_token.as_str()) == Some(rejected_token) {
+            *cached_token = None;
+        }
+    }
+}
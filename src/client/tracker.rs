@@ -0,0 +1,243 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
+use tokio::time::Instant;
+
+use crate::client::UberClient;
+use crate::errors::UberError;
+use crate::models::delivery::Delivery;
+use crate::models::delivery_state::DeliveryState;
+use crate::models::general::{CourierInfo, LatLng, Timestamp};
+
+/// Poll interval while the delivery is pending, waiting for a courier
+pub const DEFAULT_PENDING_INTERVAL: Duration = Duration::from_secs(60);
+/// Poll interval while a courier is on the way
+pub const DEFAULT_ACTIVE_INTERVAL: Duration = Duration::from_secs(20);
+/// Poll interval while `courier_imminent` is set
+pub const DEFAULT_IMMINENT_INTERVAL: Duration = Duration::from_secs(5);
+/// How long a delivery is tracked before giving up
+pub const DEFAULT_TRACKING_TIMEOUT: Duration = Duration::from_secs(4 * 60 * 60);
+
+/// Which end of the delivery an ETA refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TrackedWaypoint {
+    Pickup,
+    Dropoff,
+}
+
+/// A change noticed between two polls of a tracked delivery. The first poll reports everything already set, compared to nothing.
+#[derive(Debug, Clone)]
+pub enum TrackingEvent {
+    StatusChanged {
+        from: Option<DeliveryState>,
+        to: DeliveryState,
+    },
+    EtaChanged {
+        waypoint: TrackedWaypoint,
+        from: Option<Timestamp>,
+        to: Option<Timestamp>,
+    },
+    CourierAssigned(CourierInfo),
+    CourierLocationUpdated(LatLng),
+}
+
+/// Polls get_delivery until the delivery reaches a terminal state, built with [`UberClient::track_delivery`]
+///
+/// | Setting           | Default | Description |
+/// |-------------------|---------|-------------|
+/// | pending_interval  | 60s     | Wait between polls while the delivery is `pending`. |
+/// | active_interval   | 20s     | Wait between polls once a courier is on the way. |
+/// | imminent_interval | 5s      | Wait between polls while `courier_imminent` is set. |
+/// | timeout           | 4h      | Stop with `UberError::Timeout` if the delivery isn't finished by then, even mid-poll. |
+///
+/// Example:
+/// ```no_run
+/// # async fn run() -> Result<(), uber_api::UberError> {
+/// use futures::TryStreamExt;
+/// use uber_api::{TrackingEvent, UberClient};
+///
+/// let client = UberClient::new("cus_1234");
+/// let mut events = client.track_delivery("access_token", "del_1234").into_stream();
+///
+/// while let Some(event) = events.try_next().await? {
+///     if let TrackingEvent::StatusChanged { to, .. } = event {
+///         log::info!("Delivery is now {}", to);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct DeliveryTracker {
+    client: UberClient,
+    access_token: String,
+    delivery_id: String,
+    pending_interval: Duration,
+    active_interval: Duration,
+    imminent_interval: Duration,
+    timeout: Duration,
+}
+
+impl UberClient {
+    /// Follow a delivery by polling get_delivery, see [`DeliveryTracker`]
+    pub fn track_delivery(&self, access_token: &str, delivery_id: &str) -> DeliveryTracker {
+        DeliveryTracker {
+            client: self.clone(),
+            access_token: access_token.to_string(),
+            delivery_id: delivery_id.to_string(),
+            pending_interval: DEFAULT_PENDING_INTERVAL,
+            active_interval: DEFAULT_ACTIVE_INTERVAL,
+            imminent_interval: DEFAULT_IMMINENT_INTERVAL,
+            timeout: DEFAULT_TRACKING_TIMEOUT,
+        }
+    }
+}
+
+impl DeliveryTracker {
+    pub fn with_pending_interval(mut self, pending_interval: Duration) -> Self {
+        self.pending_interval = pending_interval;
+        self
+    }
+
+    pub fn with_active_interval(mut self, active_interval: Duration) -> Self {
+        self.active_interval = active_interval;
+        self
+    }
+
+    pub fn with_imminent_interval(mut self, imminent_interval: Duration) -> Self {
+        self.imminent_interval = imminent_interval;
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Stream the changes as they are polled. The stream ends once the delivery is terminal, or after the first error,
+    /// which is `UberError::Timeout` when the timeout runs out first.
+    pub fn into_stream(self) -> BoxStream<'static, Result<TrackingEvent, UberError>> {
+        let state = TrackingState {
+            deadline: Instant::now() + self.timeout,
+            tracker: self,
+            last: None,
+            events: VecDeque::new(),
+            finished: false,
+        };
+
+        stream::unfold(state, TrackingState::next_event).boxed()
+    }
+
+    /// Call `on_event` for each change until the delivery is terminal
+    pub async fn run<F: FnMut(TrackingEvent)>(self, mut on_event: F) -> Result<(), UberError> {
+        self.into_stream()
+            .try_for_each(|event| {
+                on_event(event);
+                futures::future::ok(())
+            })
+            .await
+    }
+
+    fn interval_for(&self, delivery: &Delivery) -> Duration {
+        if delivery.courier_imminent == Some(true) {
+            self.imminent_interval
+        } else if matches!(delivery.status, None | Some(DeliveryState::Pending)) {
+            self.pending_interval
+        } else {
+            self.active_interval
+        }
+    }
+}
+
+struct TrackingState {
+    tracker: DeliveryTracker,
+    deadline: Instant,
+    last: Option<Delivery>,
+    events: VecDeque<TrackingEvent>,
+    finished: bool,
+}
+
+impl TrackingState {
+    async fn next_event(mut self) -> Option<(Result<TrackingEvent, UberError>, Self)> {
+        loop {
+            if let Some(event) = self.events.pop_front() {
+                return Some((Ok(event), self));
+            }
+            if self.finished {
+                return None;
+            }
+
+            if let Some(last) = &self.last {
+                let wake_at = Instant::now() + self.tracker.interval_for(last);
+                if wake_at >= self.deadline {
+                    tokio::time::sleep_until(self.deadline).await;
+                    return Some(self.timed_out());
+                }
+                tokio::time::sleep_until(wake_at).await;
+            }
+
+            // a poll hanging past the deadline (retries included) times out like a delivery that isn't finished
+            let tracker = &self.tracker;
+            let poll = tracker.client.get_delivery(&tracker.access_token, &tracker.delivery_id);
+            let delivery = match tokio::time::timeout_at(self.deadline, poll).await {
+                Ok(Ok(response)) => response.data,
+                Ok(Err(err)) => {
+                    self.finished = true;
+                    return Some((Err(err), self));
+                }
+                Err(_) => return Some(self.timed_out()),
+            };
+
+            self.events.extend(changes(self.last.as_ref(), &delivery));
+            self.finished = delivery.status.as_ref().is_some_and(DeliveryState::is_terminal);
+            self.last = Some(delivery);
+        }
+    }
+
+    fn timed_out(mut self) -> (Result<TrackingEvent, UberError>, Self) {
+        self.finished = true;
+        let message = format!("delivery {} still not finished", self.tracker.delivery_id);
+        (Err(UberError::Timeout(message)), self)
+    }
+}
+
+// events for everything that differs between two polls of the same delivery
+fn changes(previous: Option<&Delivery>, current: &Delivery) -> Vec<TrackingEvent> {
+    let mut events = Vec::new();
+
+    let previous_status = previous.and_then(|delivery| delivery.status.clone());
+    if let Some(status) = &current.status {
+        if previous_status.as_ref() != Some(status) {
+            events.push(TrackingEvent::StatusChanged { from: previous_status, to: status.clone() });
+        }
+    }
+
+    let etas = [
+        (TrackedWaypoint::Pickup, previous.and_then(|delivery| delivery.pickup_eta), current.pickup_eta),
+        (TrackedWaypoint::Dropoff, previous.and_then(|delivery| delivery.dropoff_eta), current.dropoff_eta),
+    ];
+    for (waypoint, from, to) in etas {
+        if from != to {
+            events.push(TrackingEvent::EtaChanged { waypoint, from, to });
+        }
+    }
+
+    let previous_courier = previous.and_then(|delivery| delivery.courier.as_ref());
+    if let Some(courier) = &current.courier {
+        // a reassignment brings a different courier
+        let assigned = previous_courier.is_none_or(|previous| previous.name != courier.name || previous.phone_number != courier.phone_number);
+        if assigned {
+            events.push(TrackingEvent::CourierAssigned(courier.clone()));
+        }
+
+        let previous_location = previous_courier.and_then(|previous| previous.location.as_ref());
+        if let Some(location) = &courier.location {
+            if previous_location != Some(location) {
+                events.push(TrackingEvent::CourierLocationUpdated(location.clone()));
+            }
+        }
+    }
+
+    events
+}
@@ -0,0 +1,333 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+use reqwest::StatusCode;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// # Documented Uber Direct Error Codes
+///
+/// Every `code` listed in the endpoint docs (auth, create_quote, create_delivery, cancel_delivery, pod_retrieval), anything else is kept as `Unknown`.
+///
+/// | Http Status | Code | Code Message |
+/// | :--- | :--- | :--- |
+/// | 400 | invalid_request | Required parameters were not provided. |
+/// | 401 | invalid_client | The client ID or secret provided is invalid. |
+/// | 400 | invalid_scope | The scope provided is invalid |
+/// | 500 | server_error | The server returned an unknown error. |
+/// | 401 | unauthorized | Invalid OAuth 2.0 credentials provided. |
+/// | 400 | invalid_params | The parameters of your request were invalid. |
+/// | 400 | unknown_location | The specified location was not understood. |
+/// | 400 | address_undeliverable | The specified location is not in a deliverable area. |
+/// | 422 | address_undeliverable_limited_couriers | The specified location is not in a deliverable area at this time because all couriers are currently busy. |
+/// | 400 | pickup_window_too_small | The pickup window needs to be at least 10 minutes long. |
+/// | 400 | dropoff_deadline_too_early | The dropoff deadline needs to be at least 20 minutes after the dropoff ready time. |
+/// | 400 | dropoff_deadline_before_pickup_deadline | The dropoff deadline needs to be after the pickup deadline. |
+/// | 400 | dropoff_ready_after_pickup_deadline | The dropoff ready time needs to be at or before the pickup deadline. |
+/// | 400 | pickup_ready_too_early | The pickup ready time cannot be in the past. |
+/// | 400 | pickup_deadline_too_early | The pickup deadline time needs to be at least 20 minutes from now. |
+/// | 400 | pickup_ready_too_late | The pickup ready time needs to be within the next 30 days. |
+/// | 400 | pickup_ready_time_not_specified | Pickup ready time must be specified when passing in pickup/dropoff windows. |
+/// | 400 | expired_quote | The price quote specified has expired. |
+/// | 400 | used_quote | The price quote specified has already been used. |
+/// | 400 | mismatched_price_quote | The price quote specified doesn’t match the delivery. |
+/// | 400 | missing_payment | Your account’s payment information has not been provided. |
+/// | 409 | duplicate_delivery | An active delivery like this already exists. A pointer to the other delivery is provided. |
+/// | 400 | noncancelable_delivery | Delivery cannot be cancelled. |
+/// | 402 | customer_suspended | Your account is passed due. Payment is required. |
+/// | 403 | customer_blocked | Your account is not allowed to create deliveries. |
+/// | 404 | customer_not_found | Customer does not exist. |
+/// | 404 | delivery_not_found | The requested delivery does not exist. |
+/// | 408 | request_timeout | The request timed out. |
+/// | 429 | customer_limited | Your account’s limits have been exceeded. |
+/// | 500 | unknown_error | An unknown error happened. |
+/// | 503 | service_unavailable | Service is currently unavailable. |
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum UberErrorCode {
+    // Authentication
+    InvalidRequest,
+    InvalidClient,
+    InvalidScope,
+    ServerError,
+    Unauthorized,
+
+    // Request / address
+    InvalidParams,
+    UnknownLocation,
+    AddressUndeliverable,
+    AddressUndeliverableLimitedCouriers,
+
+    // Pickup / dropoff windows
+    PickupWindowTooSmall,
+    DropoffDeadlineTooEarly,
+    DropoffDeadlineBeforePickupDeadline,
+    DropoffReadyAfterPickupDeadline,
+    PickupReadyTooEarly,
+    PickupDeadlineTooEarly,
+    PickupReadyTooLate,
+    PickupReadyTimeNotSpecified,
+
+    // Quotes
+    ExpiredQuote,
+    UsedQuote,
+    MismatchedPriceQuote,
+
+    // Deliveries
+    DuplicateDelivery,
+    NoncancelableDelivery,
+    DeliveryNotFound,
+
+    // Account
+    MissingPayment,
+    CustomerSuspended,
+    CustomerBlocked,
+    CustomerNotFound,
+    CustomerLimited,
+
+    // Transient
+    RequestTimeout,
+    UnknownError,
+    ServiceUnavailable,
+
+    Unknown(String),
+}
+
+impl UberErrorCode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::InvalidRequest => "invalid_request",
+            Self::InvalidClient => "invalid_client",
+            Self::InvalidScope => "invalid_scope",
+            Self::ServerError => "server_error",
+            Self::Unauthorized => "unauthorized",
+            Self::InvalidParams => "invalid_params",
+            Self::UnknownLocation => "unknown_location",
+            Self::AddressUndeliverable => "address_undeliverable",
+            Self::AddressUndeliverableLimitedCouriers => "address_undeliverable_limited_couriers",
+            Self::PickupWindowTooSmall => "pickup_window_too_small",
+            Self::DropoffDeadlineTooEarly => "dropoff_deadline_too_early",
+            Self::DropoffDeadlineBeforePickupDeadline => "dropoff_deadline_before_pickup_deadline",
+            Self::DropoffReadyAfterPickupDeadline => "dropoff_ready_after_pickup_deadline",
+            Self::PickupReadyTooEarly => "pickup_ready_too_early",
+            Self::PickupDeadlineTooEarly => "pickup_deadline_too_early",
+            Self::PickupReadyTooLate => "pickup_ready_too_late",
+            Self::PickupReadyTimeNotSpecified => "pickup_ready_time_not_specified",
+            Self::ExpiredQuote => "expired_quote",
+            Self::UsedQuote => "used_quote",
+            Self::MismatchedPriceQuote => "mismatched_price_quote",
+            Self::DuplicateDelivery => "duplicate_delivery",
+            Self::NoncancelableDelivery => "noncancelable_delivery",
+            Self::DeliveryNotFound => "delivery_not_found",
+            Self::MissingPayment => "missing_payment",
+            Self::CustomerSuspended => "customer_suspended",
+            Self::CustomerBlocked => "customer_blocked",
+            Self::CustomerNotFound => "customer_not_found",
+            Self::CustomerLimited => "customer_limited",
+            Self::RequestTimeout => "request_timeout",
+            Self::UnknownError => "unknown_error",
+            Self::ServiceUnavailable => "service_unavailable",
+            Self::Unknown(code) => code,
+        }
+    }
+
+    /// The code Uber answers with this status, for responses without an error body.
+    /// Statuses Uber has no code for are kept as `Unknown`, e.g. `Unknown("502")`.
+    pub fn from_status(status: StatusCode) -> Self {
+        match status {
+            StatusCode::REQUEST_TIMEOUT => Self::RequestTimeout,
+            StatusCode::TOO_MANY_REQUESTS => Self::CustomerLimited,
+            StatusCode::INTERNAL_SERVER_ERROR => Self::UnknownError,
+            StatusCode::SERVICE_UNAVAILABLE => Self::ServiceUnavailable,
+            _ => Self::Unknown(status.as_u16().to_string()),
+        }
+    }
+
+    /// The documented message for this code, `None` for codes the docs don't list
+    pub fn description(&self) -> Option<&'static str> {
+        let description = match self {
+            Self::InvalidRequest => "Required parameters were not provided.",
+            Self::InvalidClient => "The client ID or secret provided is invalid.",
+            Self::InvalidScope => "The scope provided is invalid",
+            Self::ServerError => "The server returned an unknown error.",
+            Self::Unauthorized => "Invalid OAuth 2.0 credentials provided.",
+            Self::InvalidParams => "The parameters of your request were invalid.",
+            Self::UnknownLocation => "The specified location was not understood.",
+            Self::AddressUndeliverable => "The specified location is not in a deliverable area.",
+            Self::AddressUndeliverableLimitedCouriers => "The specified location is not in a deliverable area at this time because all couriers are currently busy.",
+            Self::PickupWindowTooSmall => "The pickup window needs to be at least 10 minutes long.",
+            Self::DropoffDeadlineTooEarly => "The dropoff deadline needs to be at least 20 minutes after the dropoff ready time.",
+            Self::DropoffDeadlineBeforePickupDeadline => "The dropoff deadline needs to be after the pickup deadline.",
+            Self::DropoffReadyAfterPickupDeadline => "The dropoff ready time needs to be at or before the pickup deadline.",
+            Self::PickupReadyTooEarly => "The pickup ready time cannot be in the past.",
+            Self::PickupDeadlineTooEarly => "The pickup deadline time needs to be at least 20 minutes from now.",
+            Self::PickupReadyTooLate => "The pickup ready time needs to be within the next 30 days.",
+            Self::PickupReadyTimeNotSpecified => "Pickup ready time must be specified when passing in pickup/dropoff windows.",
+            Self::ExpiredQuote => "The price quote specified has expired.",
+            Self::UsedQuote => "The price quote specified has already been used.",
+            Self::MismatchedPriceQuote => "The price quote specified doesn’t match the delivery.",
+            Self::DuplicateDelivery => "An active delivery like this already exists. A pointer to the other delivery is provided.",
+            Self::NoncancelableDelivery => "Delivery cannot be cancelled.",
+            Self::DeliveryNotFound => "The requested delivery does not exist.",
+            Self::MissingPayment => "Your account’s payment information has not been provided.",
+            Self::CustomerSuspended => "Your account is passed due. Payment is required.",
+            Self::CustomerBlocked => "Your account is not allowed to create deliveries.",
+            Self::CustomerNotFound => "Customer does not exist.",
+            Self::CustomerLimited => "Your account's limits have been exceeded.",
+            Self::RequestTimeout => "The request timed out.",
+            Self::UnknownError => "An unknown error happened.",
+            Self::ServiceUnavailable => "Service is currently unavailable.",
+            Self::Unknown(_) => return None,
+        };
+        Some(description)
+    }
+
+    /// Transient failures where sending the same request again later may succeed
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::RequestTimeout
+                | Self::CustomerLimited
+                | Self::UnknownError
+                | Self::ServiceUnavailable
+                | Self::ServerError
+        )
+    }
+
+    /// The quote_id sent with the delivery can't be used, a new quote is needed
+    pub fn is_quote_problem(&self) -> bool {
+        matches!(
+            self,
+            Self::ExpiredQuote
+                | Self::UsedQuote
+                | Self::MismatchedPriceQuote
+        )
+    }
+
+    /// The pickup or dropoff address can't be understood or served
+    pub fn is_address_problem(&self) -> bool {
+        matches!(
+            self,
+            Self::UnknownLocation
+                | Self::AddressUndeliverable
+                | Self::AddressUndeliverableLimitedCouriers
+        )
+    }
+
+    /// The pickup/dropoff ready and deadline times break one of the window rules
+    pub fn is_time_window_problem(&self) -> bool {
+        matches!(
+            self,
+            Self::PickupWindowTooSmall
+                | Self::DropoffDeadlineTooEarly
+                | Self::DropoffDeadlineBeforePickupDeadline
+                | Self::DropoffReadyAfterPickupDeadline
+                | Self::PickupReadyTooEarly
+                | Self::PickupDeadlineTooEarly
+                | Self::PickupReadyTooLate
+                | Self::PickupReadyTimeNotSpecified
+        )
+    }
+
+    /// Something about the Uber account itself needs attention (billing, limits, access)
+    pub fn is_account_problem(&self) -> bool {
+        matches!(
+            self,
+            Self::MissingPayment
+                | Self::CustomerSuspended
+                | Self::CustomerBlocked
+                | Self::CustomerNotFound
+                | Self::CustomerLimited
+        )
+    }
+
+    /// The client credentials or access token were rejected
+    pub fn is_auth_problem(&self) -> bool {
+        matches!(
+            self,
+            Self::InvalidRequest
+                | Self::InvalidClient
+                | Self::InvalidScope
+                | Self::Unauthorized
+        )
+    }
+}
+
+impl From<&str> for UberErrorCode {
+    fn from(code: &str) -> Self {
+        match code {
+            "invalid_request" => Self::InvalidRequest,
+            "invalid_client" => Self::InvalidClient,
+            "invalid_scope" => Self::InvalidScope,
+            "server_error" => Self::ServerError,
+            "unauthorized" => Self::Unauthorized,
+            "invalid_params" => Self::InvalidParams,
+            "unknown_location" => Self::UnknownLocation,
+            "address_undeliverable" => Self::AddressUndeliverable,
+            "address_undeliverable_limited_couriers" => Self::AddressUndeliverableLimitedCouriers,
+            "pickup_window_too_small" => Self::PickupWindowTooSmall,
+            "dropoff_deadline_too_early" => Self::DropoffDeadlineTooEarly,
+            "dropoff_deadline_before_pickup_deadline" => Self::DropoffDeadlineBeforePickupDeadline,
+            "dropoff_ready_after_pickup_deadline" => Self::DropoffReadyAfterPickupDeadline,
+            "pickup_ready_too_early" => Self::PickupReadyTooEarly,
+            "pickup_deadline_too_early" => Self::PickupDeadlineTooEarly,
+            "pickup_ready_too_late" => Self::PickupReadyTooLate,
+            "pickup_ready_time_not_specified" => Self::PickupReadyTimeNotSpecified,
+            "expired_quote" => Self::ExpiredQuote,
+            "used_quote" => Self::UsedQuote,
+            "mismatched_price_quote" => Self::MismatchedPriceQuote,
+            "duplicate_delivery" => Self::DuplicateDelivery,
+            "noncancelable_delivery" => Self::NoncancelableDelivery,
+            "delivery_not_found" => Self::DeliveryNotFound,
+            "missing_payment" => Self::MissingPayment,
+            "customer_suspended" => Self::CustomerSuspended,
+            "customer_blocked" => Self::CustomerBlocked,
+            // the docs spell it customer_not_cound in places
+            "customer_not_found" | "customer_not_cound" => Self::CustomerNotFound,
+            "customer_limited" => Self::CustomerLimited,
+            "request_timeout" => Self::RequestTimeout,
+            "unknown_error" => Self::UnknownError,
+            "service_unavailable" => Self::ServiceUnavailable,
+            other => Self::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl From<String> for UberErrorCode {
+    fn from(code: String) -> Self {
+        Self::from(code.as_str())
+    }
+}
+
+impl FromStr for UberErrorCode {
+    type Err = Infallible;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(code))
+    }
+}
+
+impl fmt::Display for UberErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for UberErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for UberErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let code = String::deserialize(deserializer)?;
+        Ok(Self::from(code))
+    }
+}
@@ -1,7 +1,11 @@
 use std::string::ToString;
 
+use reqwest::StatusCode;
+use serde::Deserialize;
 use thiserror::Error;
 
+use crate::errors::UberErrorCode;
+
 // pub(crate) type UberResult<T> = Result<T, UberError>;
 
 pub trait UnwrapRequestField<T> {
@@ -20,6 +24,27 @@ impl<T> UnwrapRequestField<T> for Option<T> {
     }
 }
 
+/// # Error Response Body
+///
+/// |Name| Type| Description|
+/// | :--- | :--- | :--- |
+/// |code| string| Uber error code, e.g. invalid_params, duplicate_delivery, customer_limited.|
+/// |message| string| Human readable description of the error.|
+/// |metadata| object| Extra details about the error, e.g. the pointer to the other delivery for duplicate_delivery.|
+///
+/// The OAuth token endpoint uses `error` / `error_description` instead of `code` / `message`, both shapes are accepted.
+///
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct ErrorResponse {
+    #[serde(alias = "error")]
+    pub code: UberErrorCode,
+    #[serde(default, alias = "error_description")]
+    pub message: String,
+    #[serde(default)]
+    pub metadata: Option<serde_json::Value>,
+}
+
 /// Error enum for all cases of internal/external errors occurring during client execution
 #[derive(Error, Debug)]
 pub enum UberError {
@@ -45,6 +70,36 @@ pub enum UberError {
     #[error("invalid header (expected {expected:?}, found {found:?})")]
     InvalidRequest { expected: String, found: String },
 
+    // Non-2xx responses from Uber, parsed from their error envelope
+    #[error("Uber API error {status} ({code}) - {message}")]
+    Api {
+        status: StatusCode,
+        code: UberErrorCode,
+        message: String,
+        metadata: Option<serde_json::Value>,
+    },
+
+    // Requests rejected before being sent, with the code Uber would have answered
+    #[error("Validation error ({code}) - {message}")]
+    Validation {
+        code: UberErrorCode,
+        message: String,
+    },
+    #[error("Currency mismatch (expected {expected}, found {found})")]
+    CurrencyMismatch { expected: String, found: String },
+    // A booking that doesn't match the quote shown, `delivery_id` is set when the delivery was created anyway
+    #[error("Quote mismatch ({quote_id}) - {message}")]
+    QuoteMismatch {
+        quote_id: String,
+        message: String,
+        delivery_id: Option<String>,
+    },
+    // Webhook requests that can't be shown to come from Uber
+    #[error("Missing webhook signature - {0}")]
+    MissingSignature(String),
+    #[error("Invalid webhook signature - {0}")]
+    InvalidSignature(String),
+
     // Errors converted from others
     #[error("Json error - {0:?}")]
     JsonError(#[from] serde_json::Error),
@@ -56,6 +111,10 @@ pub enum UberError {
     HeaderValueError(#[from] reqwest::header::InvalidHeaderValue),
     #[error("ParseError - {0:?}")]
     ParseError(#[from] chrono::ParseError),
+    #[error("Base64 error - {0:?}")]
+    Base64Error(#[from] base64::DecodeError),
+    #[error("Io error - {0:?}")]
+    IoError(#[from] std::io::Error),
     #[error(transparent)]
     Other(#[from] anyhow::Error),
 }
@@ -84,6 +143,25 @@ impl UberError {
         }
     }
 
+    /// Build an `UberError::Api` from a non-2xx response. Bodies that aren't Uber's error envelope (e.g. from a proxy) keep the raw body
+    /// as the message, with the code Uber uses for the status so retries still classify them, see [`UberErrorCode::from_status`].
+    pub fn from_response(status: StatusCode, response_body: &str) -> Self {
+        match serde_json::from_str::<ErrorResponse>(response_body) {
+            Ok(error_response) => Self::Api {
+                status,
+                code: error_response.code,
+                message: error_response.message,
+                metadata: error_response.metadata,
+            },
+            Err(_) => Self::Api {
+                status,
+                code: UberErrorCode::from_status(status),
+                message: response_body.to_string(),
+                metadata: None,
+            },
+        }
+    }
+
     pub fn get_code(&self) -> String {
         match self {
             Self::Unauthorized(_) => UNAUTHORIZED.to_string(),
@@ -94,8 +172,57 @@ impl UberError {
             Self::InternalServerError(_) => INTERNAL_SERVER_ERROR.to_string(),
             Self::Exists(_) => EXISTS.to_string(),
             Self::NotImplemented(_) => NOT_IMPLEMENTED.to_string(),
+            Self::Api { code, .. } | Self::Validation { code, .. } => code.to_string(),
+            Self::QuoteMismatch { .. } => UberErrorCode::MismatchedPriceQuote.to_string(),
+            Self::MissingSignature(_) | Self::InvalidSignature(_) => UNAUTHORIZED.to_string(),
             _ => INTERNAL_SERVER_ERROR.to_string(),
         }
     }
+
+    /// The Uber error code for `UberError::Api`, or the code Uber would have answered for `UberError::Validation`.
+    /// `None` for other errors that never reached Uber or weren't a non-2xx response
+    pub fn error_code(&self) -> Option<&UberErrorCode> {
+        match self {
+            Self::Api { code, .. } | Self::Validation { code, .. } => Some(code),
+            _ => None,
+        }
+    }
+
+    /// For a `duplicate_delivery` error, the id of the delivery that already exists, taken from the error metadata (or the message as a fallback)
+    pub fn duplicate_delivery_id(&self) -> Option<String> {
+        match self {
+            Self::Api { code: UberErrorCode::DuplicateDelivery, message, metadata, .. } => metadata
+                .as_ref()
+                .and_then(find_delivery_id)
+                .or_else(|| {
+                    message
+                        .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                        .find(|word| word.starts_with("del_"))
+                        .map(str::to_string)
+                }),
+            _ => None,
+        }
+    }
+
+    /// Whether the access token was rejected, in which case re-authenticating may fix the call
+    pub fn is_unauthorized(&self) -> bool {
+        match self {
+            Self::Unauthorized(_) => true,
+            Self::Api { status, .. } => *status == StatusCode::UNAUTHORIZED,
+            _ => false,
+        }
+    }
 }
 
+/// Look for the pointer to the other delivery in duplicate_delivery metadata, preferring the usual id keys over any `del_` value
+fn find_delivery_id(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::String(id) if id.starts_with("del_") => Some(id.clone()),
+        serde_json::Value::Object(map) => ["delivery_id", "existing_delivery_id", "id"]
+            .iter()
+            .find_map(|key| map.get(*key).and_then(|id| id.as_str()).map(str::to_string))
+            .or_else(|| map.values().find_map(find_delivery_id)),
+        serde_json::Value::Array(values) => values.iter().find_map(find_delivery_id),
+        _ => None,
+    }
+}
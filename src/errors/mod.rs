@@ -1,3 +1,6 @@
 pub use errors::*;
+pub use error_code::*;
 
+#[allow(clippy::module_inception)]
 pub(crate) mod errors;
+mod error_code;
\ No newline at end of file
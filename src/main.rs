@@ -1,59 +1,138 @@
-use std::default;
-
 use uber_api::{
-    create_delivery, 
-    auth,
-    create_quote, 
-    get_delivery,
-    update_delivery, 
-    cancel_delivery,
-    list_deliveries,
-    pod_retrieval,
+    UberClient,
     AuthRequest, 
-    CreateDeliveryRequest,
-    UpdateDeliveryRequest,
-    CreateQuoteRequest, 
-    PODRetrievalRequest,
-    models::general::{
-        ManifestItem, 
-        RoboCourierSpecification, 
-        TestSpecifications,
-        StructuredAddress, 
-        VerificationRequirement,
-        SignatureRequirement
+    UberError,
+    models::general::Timestamp,
+    models::webhooks::{
+        read_journal,
+        replay_journal,
+        ReplayFilter,
+        WebhookEvent,
+        WebhookHandler
     }
 };
 
+use std::path::PathBuf;
+
 use clap::Parser;
+use reqwest::header::{CONTENT_LENGTH, HOST};
 
 #[derive(clap::StructOpt, Debug)]
 #[structopt(
 about = "Uber client",
 name = "uber-client",
 version = "0.1.0",
+subcommand_negates_reqs = true,
 )]
 struct CmdArgs {
-    #[structopt(long)]
-    client_id: String,
-    #[structopt(long)]
-    client_secret: String,
-    #[structopt(long)]
-    customer_id: String,
+    #[structopt(long, required = true)]
+    client_id: Option<String>,
+    #[structopt(long, required = true)]
+    client_secret: Option<String>,
+    #[structopt(long, required = true)]
+    customer_id: Option<String>,
+    #[structopt(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(clap::StructOpt, Debug)]
+enum Command {
+    /// Re-feed journaled webhooks, POSTing them to a webhook endpoint or printing them when no --url is given
+    ReplayWebhooks {
+        /// JSON Lines journal written by WebhookJournal
+        #[structopt(long)]
+        journal: PathBuf,
+        /// Webhook endpoint to POST the journaled bodies and headers to
+        #[structopt(long)]
+        url: Option<String>,
+        /// Only webhooks received at or after this RFC 3339 time
+        #[structopt(long)]
+        from: Option<Timestamp>,
+        /// Only webhooks received before this RFC 3339 time
+        #[structopt(long)]
+        until: Option<Timestamp>,
+        #[structopt(long)]
+        delivery_id: Option<String>,
+        /// e.g. event.delivery_status
+        #[structopt(long)]
+        kind: Option<String>,
+    },
+}
+
+// prints every replayed event
+struct PrintEvents;
+
+impl WebhookHandler for PrintEvents {
+    async fn handle(&self, event: WebhookEvent) -> Result<(), UberError> {
+        println!("{} {:?} => {:#?}", event.kind(), event.delivery_id(), event);
+        Ok(())
+    }
+}
+
+async fn replay_webhooks(journal: PathBuf, url: Option<String>, filter: ReplayFilter) -> Result<(), Box<dyn std::error::Error>> {
+    let url = match url {
+        Some(url) => url,
+        None => {
+            let report = replay_journal(&journal, &filter, &PrintEvents).await?;
+            println!("Replayed {} webhooks, skipped {}", report.replayed, report.skipped);
+            for failure in report.failures {
+                println!("Line {} failed => {}", failure.line, failure.error);
+            }
+            return Ok(());
+        }
+    };
+
+    let http_client = reqwest::Client::new();
+    for (line, entry) in read_journal(&journal, &filter).await? {
+        let mut headers = entry.header_map();
+        headers.remove(HOST);
+        headers.remove(CONTENT_LENGTH);
+
+        let response = http_client.post(&url).headers(headers).body(entry.body).send().await?;
+        println!("Line {} received {} => {}", line, entry.received, response.status());
+    }
+
+    Ok(())
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let CmdArgs {
-        client_id, client_secret, customer_id,
+        client_id, client_secret, customer_id, command,
     } = CmdArgs::parse();
+
+    if let Some(Command::ReplayWebhooks { journal, url, from, until, delivery_id, kind }) = command {
+        let mut filter = ReplayFilter::new();
+        if let Some(from) = from {
+            filter = filter.with_from(from);
+        }
+        if let Some(until) = until {
+            filter = filter.with_until(until);
+        }
+        if let Some(delivery_id) = delivery_id {
+            filter = filter.with_delivery_id(delivery_id);
+        }
+        if let Some(kind) = kind {
+            filter = filter.with_kind(kind);
+        }
+        return replay_webhooks(journal, url, filter).await;
+    }
+
+    // clap requires all three when no subcommand is given
+    let (Some(client_id), Some(client_secret), Some(customer_id)) = (client_id, client_secret, customer_id) else {
+        return Err("--client-id, --client-secret and --customer-id are required".into());
+    };
     println!(" client id => {}\n client_secret => {}\n customer_id => {}", client_id, client_secret, customer_id);
 
+    let client = UberClient::new(&customer_id);
+
     let auth_request = AuthRequest::new(&client_id, &client_secret);
-    let auth_response = auth(auth_request).await?;
-    let access_token = &auth_response.access_token;
+    let auth_response = client.auth(auth_request).await?;
 
     println!("Access Key: => '{:#?}'", &auth_response);
 
+    // let access_token = &auth_response.data.access_token;
+
     // let street_address_1 = "123 Main St".to_string();
     // let street_address_2 = "".to_string();
     // let sublocality_level_1 = "".to_string();
@@ -65,8 +144,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     //     zip_code: "94103".to_string(),
     //     country: Some("US".to_string()),
     // };
-    // let body = serde_json::to_string(&dropoff_address)?;
-    // println!("json => {}",body);
     // let dropoff_name = "Dropoff Location";
     // let dropoff_phone_number = "+1-555-555-5555";
     // let manifest_items = vec![ManifestItem::new("Robin", 1, "small")];
@@ -99,11 +176,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     //     ..Default::default()
     // };
     // let create_delivery_request = CreateDeliveryRequest{
-    //     dropoff_address: serde_json::to_string(&dropoff_address).unwrap(),
+    //     dropoff_address: dropoff_address.clone().into(),
     //     dropoff_name: dropoff_name.to_string(),
     //     dropoff_phone_number: dropoff_phone_number.to_string(),
     //     manifest_items,
-    //     pickup_address: serde_json::to_string(&pickup_address).unwrap(),
+    //     pickup_address: pickup_address.clone().into(),
     //     pickup_name: pickup_name.to_string(),
     //     pickup_phone_number: pickup_phone_number.to_string(),
     //     deliverable_action: Some("deliverable_action_meet_at_door".to_string()),
@@ -112,47 +189,47 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     //     undeliverable_action: Some("return".to_string()),
     //     ..Default::default()
     // };
-    // let create_delivery_response = create_delivery(&access_token, &customer_id, create_delivery_request).await?;
+    // let create_delivery_response = client.create_delivery(access_token, create_delivery_request).await?;
 
     // println!("Create Delivery Response => {:#?}", &create_delivery_response);
 
     // let create_quote_request = CreateQuoteRequest::new(pickup_address, dropoff_address);
 
-    // let create_quote_response = create_quote(&access_token, &customer_id, create_quote_request).await?;
+    // let create_quote_response = client.create_quote(access_token, create_quote_request).await?;
 
     // println!("Create Quote Response => {:#?}", &create_quote_response);
 
-    // let delivery_id = create_delivery_response.id.unwrap();
+    // let delivery_id = create_delivery_response.data.id.unwrap();
 
-    // let get_delivery_response = get_delivery(&access_token, &customer_id, &delivery_id).await?;
+    // let get_delivery_response = client.get_delivery(access_token, &delivery_id).await?;
 
     // println!("Get Delivery Response => {:#?}", &get_delivery_response);
 
     // let update_delivery_request = UpdateDeliveryRequest{
-    //     tip_by_customer: Some(5),
+    //     tip_by_customer: Some(Money::new(5, "USD")),
     //     ..Default::default()
     // };
 
-    // let update_delivery_response = update_delivery(&access_token, &customer_id, &delivery_id, update_delivery_request).await?;
+    // let update_delivery_response = client.update_delivery(access_token, &delivery_id, update_delivery_request).await?;
 
     // println!("Update Delivery Response => {:#?}", &update_delivery_response);
 
-    // let cancel_delivery_response = cancel_delivery(&access_token, &customer_id, &delivery_id).await?;
+    // let cancel_delivery_response = client.cancel_delivery(access_token, &delivery_id).await?;
 
     // println!("Cancel Delivery Response => {:#?}", &cancel_delivery_response);
 
-    // let list_deliveries_response = list_deliveries(&access_token, &customer_id, None, Some(1), None).await?;
+    // let list_deliveries_response = client.list_deliveries(access_token, &ListDeliveriesQuery::new().with_limit(1)).await?;
 
     // println!("List Deliveries Response => {:#?}", &list_deliveries_response);
 
-    // let pod_retrieval_request = PODRetrievalRequest {
-    //     waypoint: "dropoff".to_string(),
-    //     type_of_waypoint: "signature".to_string(),
-    // };
+    // let pod_retrieval_request = PODRetrievalRequest::new(PodWaypoint::Dropoff, PodType::Signature);
 
-    // let pod_retrieval_response = pod_retrieval(&access_token, &customer_id, &delivery_id, pod_retrieval_request).await?;
+    // let pod_retrieval_response = client.pod_retrieval(access_token, &delivery_id, pod_retrieval_request).await?;
 
     // println!("Pod Retrieval Response => {:#?}", &pod_retrieval_response);
 
+    // let signature = client.proof_of_delivery(access_token, &delivery_id, PodWaypoint::Dropoff, PodType::Signature).await?;
+    // signature.save(format!("{}_signature.{}", delivery_id, signature.format().extension())).await?;
+
     Ok(())
 }
\ No newline at end of file
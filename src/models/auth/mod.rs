@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 mod auth;
 
 pub use auth::*;
\ No newline at end of file
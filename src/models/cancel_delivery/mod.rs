@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 mod cancel_delivery;
 
 pub use cancel_delivery::*;
\ No newline at end of file
@@ -0,0 +1,342 @@
+use crate::errors::UberError;
+use crate::models::create_delivery::CreateDeliveryRequest;
+use crate::models::money::Money;
+use crate::models::general::{
+    Address,
+    Timestamp,
+    ManifestItem,
+    VerificationRequirement,
+    TestSpecifications,
+};
+
+/// Max length of the pickup/dropoff notes fields
+pub const MAX_NOTES_LENGTH: usize = 280;
+
+pub const DELIVERABLE_ACTION_MEET_AT_DOOR: &str = "deliverable_action_meet_at_door";
+pub const DELIVERABLE_ACTION_LEAVE_AT_DOOR: &str = "deliverable_action_leave_at_door";
+pub const UNDELIVERABLE_ACTION_LEAVE_AT_DOOR: &str = "leave_at_door";
+pub const UNDELIVERABLE_ACTION_RETURN: &str = "return";
+
+const MANIFEST_ITEM_SIZES: [&str; 5] = ["small", "medium", "large", "xlarge", "big"];
+
+/// Builds a `CreateDeliveryRequest`, checking the documented constraints before anything is sent to Uber
+///
+/// `build()` returns `UberError::BadInput` naming the offending field, e.g. `dropoff_notes: must be at most 280 characters (got 312)`
+/// or `manifest_items[1].quantity: must be at least 1`.
+///
+/// Example:
+/// ```
+/// use uber_api::{CreateDeliveryRequest, models::general::ManifestItem};
+///
+/// let create_delivery_request = CreateDeliveryRequest::builder()
+///     .with_pickup("Pickup Location", "456 Market St, San Francisco, CA, 94103", "+15555555555")
+///     .with_dropoff("Dropoff Location", "123 Main St, San Francisco, CA, 94103", "+15555555555")
+///     .with_manifest_item(ManifestItem::new("Robin", 1, "small"))
+///     .with_dropoff_notes("Ring the bell")
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Default)]
+pub struct CreateDeliveryRequestBuilder {
+    request: CreateDeliveryRequest,
+}
+
+impl CreateDeliveryRequest {
+    pub fn builder() -> CreateDeliveryRequestBuilder {
+        CreateDeliveryRequestBuilder::default()
+    }
+
+    /// Check the documented constraints, see [`CreateDeliveryRequestBuilder`]
+    pub fn validate(&self) -> Result<(), UberError> {
+        require_non_empty("pickup_name", &self.pickup_name)?;
+        require_address("pickup_address", &self.pickup_address)?;
+        require_non_empty("pickup_phone_number", &self.pickup_phone_number)?;
+        require_non_empty("dropoff_name", &self.dropoff_name)?;
+        require_address("dropoff_address", &self.dropoff_address)?;
+        require_non_empty("dropoff_phone_number", &self.dropoff_phone_number)?;
+
+        if self.manifest_items.is_empty() {
+            return Err(bad_input("manifest_items", "must contain at least one item"));
+        }
+        for (index, item) in self.manifest_items.iter().enumerate() {
+            validate_manifest_item(index, item)?;
+        }
+
+        check_notes("pickup_notes", &self.pickup_notes)?;
+        check_notes("dropoff_notes", &self.dropoff_notes)?;
+        check_notes("dropoff_seller_notes", &self.dropoff_seller_notes)?;
+
+        check_coordinates("pickup", self.pickup_latitude, self.pickup_longitude)?;
+        check_coordinates("dropoff", self.dropoff_latitude, self.dropoff_longitude)?;
+
+        if self.manifest_total_value.as_ref().is_some_and(Money::is_negative) {
+            return Err(bad_input("manifest_total_value", "must not be negative"));
+        }
+        if self.tip.as_ref().is_some_and(Money::is_negative) {
+            return Err(bad_input("tip", "must not be negative"));
+        }
+        // both are sent as bare cents, in the account's currency
+        if let (Some(manifest_total_value), Some(tip)) = (&self.manifest_total_value, &self.tip) {
+            if tip.currency() != manifest_total_value.currency() {
+                return Err(bad_input("tip", &format!(
+                    "currency {} doesn't match manifest_total_value currency {}",
+                    tip.currency(),
+                    manifest_total_value.currency()
+                )));
+            }
+        }
+
+        let dropoff_verified = self.dropoff_verification
+            .as_ref()
+            .and_then(signature_or_id_requirement);
+
+        match self.deliverable_action.as_deref() {
+            None | Some(DELIVERABLE_ACTION_MEET_AT_DOOR) => {}
+            Some(DELIVERABLE_ACTION_LEAVE_AT_DOOR) => {
+                if let Some(requirement) = dropoff_verified {
+                    return Err(bad_input(
+                        "deliverable_action",
+                        &format!("can't leave at door when dropoff_verification.{} is required", requirement),
+                    ));
+                }
+            }
+            Some(other) => return Err(bad_input(
+                "deliverable_action",
+                &format!("must be {} or {} (got '{}')", DELIVERABLE_ACTION_MEET_AT_DOOR, DELIVERABLE_ACTION_LEAVE_AT_DOOR, other),
+            )),
+        }
+
+        match self.undeliverable_action.as_deref() {
+            None | Some(UNDELIVERABLE_ACTION_RETURN) => {}
+            Some(UNDELIVERABLE_ACTION_LEAVE_AT_DOOR) => {
+                if let Some(requirement) = dropoff_verified {
+                    return Err(bad_input(
+                        "undeliverable_action",
+                        &format!("can't leave at door when dropoff_verification.{} is required", requirement),
+                    ));
+                }
+            }
+            Some(other) => return Err(bad_input(
+                "undeliverable_action",
+                &format!("must be {} or {} (got '{}')", UNDELIVERABLE_ACTION_LEAVE_AT_DOOR, UNDELIVERABLE_ACTION_RETURN, other),
+            )),
+        }
+
+        Ok(())
+    }
+}
+
+impl CreateDeliveryRequestBuilder {
+    /// Validate and return the request
+    pub fn build(self) -> Result<CreateDeliveryRequest, UberError> {
+        self.request.validate()?;
+        Ok(self.request)
+    }
+
+    pub fn with_pickup<T: Into<String>, A: Into<Address>>(mut self, name: T, address: A, phone_number: T) -> Self {
+        self.request.pickup_name = name.into();
+        self.request.pickup_address = address.into();
+        self.request.pickup_phone_number = phone_number.into();
+        self
+    }
+
+    pub fn with_dropoff<T: Into<String>, A: Into<Address>>(mut self, name: T, address: A, phone_number: T) -> Self {
+        self.request.dropoff_name = name.into();
+        self.request.dropoff_address = address.into();
+        self.request.dropoff_phone_number = phone_number.into();
+        self
+    }
+
+    pub fn with_manifest_item(mut self, manifest_item: ManifestItem) -> Self {
+        self.request.manifest_items.push(manifest_item);
+        self
+    }
+
+    pub fn with_manifest_items(mut self, manifest_items: Vec<ManifestItem>) -> Self {
+        self.request.manifest_items = manifest_items;
+        self
+    }
+
+    pub fn with_deliverable_action<T: Into<String>>(mut self, deliverable_action: T) -> Self {
+        self.request.deliverable_action = Some(deliverable_action.into());
+        self
+    }
+
+    pub fn with_undeliverable_action<T: Into<String>>(mut self, undeliverable_action: T) -> Self {
+        self.request.undeliverable_action = Some(undeliverable_action.into());
+        self
+    }
+
+    pub fn with_pickup_business_name<T: Into<String>>(mut self, pickup_business_name: T) -> Self {
+        self.request.pickup_business_name = Some(pickup_business_name.into());
+        self
+    }
+
+    pub fn with_dropoff_business_name<T: Into<String>>(mut self, dropoff_business_name: T) -> Self {
+        self.request.dropoff_business_name = Some(dropoff_business_name.into());
+        self
+    }
+
+    pub fn with_pickup_coordinates(mut self, latitude: f64, longitude: f64) -> Self {
+        self.request.pickup_latitude = Some(latitude);
+        self.request.pickup_longitude = Some(longitude);
+        self
+    }
+
+    pub fn with_dropoff_coordinates(mut self, latitude: f64, longitude: f64) -> Self {
+        self.request.dropoff_latitude = Some(latitude);
+        self.request.dropoff_longitude = Some(longitude);
+        self
+    }
+
+    pub fn with_pickup_notes<T: Into<String>>(mut self, pickup_notes: T) -> Self {
+        self.request.pickup_notes = Some(pickup_notes.into());
+        self
+    }
+
+    pub fn with_dropoff_notes<T: Into<String>>(mut self, dropoff_notes: T) -> Self {
+        self.request.dropoff_notes = Some(dropoff_notes.into());
+        self
+    }
+
+    pub fn with_dropoff_seller_notes<T: Into<String>>(mut self, dropoff_seller_notes: T) -> Self {
+        self.request.dropoff_seller_notes = Some(dropoff_seller_notes.into());
+        self
+    }
+
+    pub fn with_pickup_verification(mut self, pickup_verification: VerificationRequirement) -> Self {
+        self.request.pickup_verification = Some(pickup_verification);
+        self
+    }
+
+    pub fn with_dropoff_verification(mut self, dropoff_verification: VerificationRequirement) -> Self {
+        self.request.dropoff_verification = Some(dropoff_verification);
+        self
+    }
+
+    pub fn with_return_verification(mut self, return_verification: VerificationRequirement) -> Self {
+        self.request.return_verification = Some(return_verification);
+        self
+    }
+
+    pub fn with_manifest_reference<T: Into<String>>(mut self, manifest_reference: T) -> Self {
+        self.request.manifest_reference = Some(manifest_reference.into());
+        self
+    }
+
+    pub fn with_external_id<T: Into<String>>(mut self, external_id: T) -> Self {
+        self.request.external_id = Some(external_id.into());
+        self
+    }
+
+    pub fn with_manifest_total_value(mut self, manifest_total_value: Money) -> Self {
+        self.request.manifest_total_value = Some(manifest_total_value);
+        self
+    }
+
+    pub fn with_quote_id<T: Into<String>>(mut self, quote_id: T) -> Self {
+        self.request.quote_id = Some(quote_id.into());
+        self
+    }
+
+    pub fn with_pickup_window(mut self, pickup_ready_dt: Timestamp, pickup_deadline_dt: Timestamp) -> Self {
+        self.request.pickup_ready_dt = Some(pickup_ready_dt);
+        self.request.pickup_deadline_dt = Some(pickup_deadline_dt);
+        self
+    }
+
+    pub fn with_dropoff_window(mut self, dropoff_ready_dt: Timestamp, dropoff_deadline_dt: Timestamp) -> Self {
+        self.request.dropoff_ready_dt = Some(dropoff_ready_dt);
+        self.request.dropoff_deadline_dt = Some(dropoff_deadline_dt);
+        self
+    }
+
+    pub fn with_tip(mut self, tip: Money) -> Self {
+        self.request.tip = Some(tip);
+        self
+    }
+
+    pub fn with_idempotency_key<T: Into<String>>(mut self, idempotency_key: T) -> Self {
+        self.request.idempotency_key = Some(idempotency_key.into());
+        self
+    }
+
+    pub fn with_external_store_id<T: Into<String>>(mut self, external_store_id: T) -> Self {
+        self.request.external_store_id = Some(external_store_id.into());
+        self
+    }
+
+    pub fn with_test_specifications(mut self, test_specifications: TestSpecifications) -> Self {
+        self.request.test_specifications = Some(test_specifications);
+        self
+    }
+}
+
+fn bad_input(field: &str, problem: &str) -> UberError {
+    UberError::BadInput(format!("{}: {}", field, problem))
+}
+
+fn require_non_empty(field: &str, value: &str) -> Result<(), UberError> {
+    if value.trim().is_empty() {
+        return Err(bad_input(field, "is required"));
+    }
+    Ok(())
+}
+
+fn require_address(field: &str, address: &Address) -> Result<(), UberError> {
+    if address.is_empty() {
+        return Err(bad_input(field, "is required"));
+    }
+    Ok(())
+}
+
+fn check_notes(field: &str, notes: &Option<String>) -> Result<(), UberError> {
+    let length = notes.as_deref().map_or(0, |notes| notes.chars().count());
+    if length > MAX_NOTES_LENGTH {
+        return Err(bad_input(field, &format!("must be at most {} characters (got {})", MAX_NOTES_LENGTH, length)));
+    }
+    Ok(())
+}
+
+fn check_coordinates(waypoint: &str, latitude: Option<f64>, longitude: Option<f64>) -> Result<(), UberError> {
+    if let Some(latitude) = latitude {
+        if !(-90.0..=90.0).contains(&latitude) {
+            return Err(bad_input(&format!("{}_latitude", waypoint), &format!("must be between -90 and 90 (got {})", latitude)));
+        }
+    }
+    if let Some(longitude) = longitude {
+        if !(-180.0..=180.0).contains(&longitude) {
+            return Err(bad_input(&format!("{}_longitude", waypoint), &format!("must be between -180 and 180 (got {})", longitude)));
+        }
+    }
+    Ok(())
+}
+
+fn validate_manifest_item(index: usize, item: &ManifestItem) -> Result<(), UberError> {
+    let field = |name: &str| format!("manifest_items[{}].{}", index, name);
+
+    if item.name.trim().is_empty() {
+        return Err(bad_input(&field("name"), "is required"));
+    }
+    if item.quantity == 0 {
+        return Err(bad_input(&field("quantity"), "must be at least 1"));
+    }
+    if !item.size.is_empty() && !MANIFEST_ITEM_SIZES.contains(&item.size.as_str()) {
+        return Err(bad_input(&field("size"), &format!("must be one of small, medium, large, xlarge, big (got '{}')", item.size)));
+    }
+    if item.price.as_ref().is_some_and(Money::is_negative) {
+        return Err(bad_input(&field("price"), "must not be negative"));
+    }
+    Ok(())
+}
+
+/// The dropoff verification that rules out leaving the delivery at the door, if any
+fn signature_or_id_requirement(verification: &VerificationRequirement) -> Option<&'static str> {
+    if verification.signature_requirement.as_ref().is_some_and(|signature| signature.enabled) {
+        return Some("signature_requirement");
+    }
+    if verification.identification.is_some() {
+        return Some("identification");
+    }
+    None
+}
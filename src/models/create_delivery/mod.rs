@@ -1,3 +1,6 @@
+mod builder;
+#[allow(clippy::module_inception)]
 mod create_delivery;
 
+pub use builder::*;
 pub use create_delivery::*;
\ No newline at end of file
@@ -1,15 +1,18 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
+use crate::models::create_delivery::CreateDeliveryRequest;
+use crate::models::money::{minor_units, Money};
 use crate::models::general::{
-    LocalDateTime,
+    Address,
+    Timestamp,
 };
 
 /// # Request Body Parameters
 ///
 /// | Name | Type | Description |
 /// | :--- | :--- | :--- |
-/// | dropoff_address | string | (required) Dropoff address in Street Address, City, State, Zip format
-/// | pickup_address | string | (required) Pickup address in Street Address, City, State, Zip format
+/// | dropoff_address | Address | (required) Dropoff address in Street Address, City, State, Zip format, or a StructuredAddress
+/// | pickup_address | Address | (required) Pickup address in Street Address, City, State, Zip format, or a StructuredAddress
 /// | dropoff_latitude | double | Dropoff latitude coordinate
 /// | dropoff_longitude | double | Dropoff longitude coordinate
 /// | dropoff_phone_number | string | Phone number of recipient |
@@ -20,14 +23,14 @@ use crate::models::general::{
 /// | pickup_deadline_dt | timestamp (RFC 3339) | End of the window when an order may be picked up. Must be at least 10 mins later than pickup_ready_dt and at least 20 minutes in the future from now. |
 /// | dropoff_ready_dt | timestamp (RFC 3339) | Beginning of the window when an order must be dropped off. Must be less than or equal to pickup_deadline_dt |
 /// | dropoff_deadline_dt | timestamp (RFC 3339) | End of the window when an order must be dropped off. Must be at least 20 mins later than dropoff_ready_dt and must be greater than or equal to pickup_deadline_dt. |
-/// | manifest_total_value | integer | Value (in US cents) of the items in the delivery. i.e.: $10.99 => 1099. |
+/// | manifest_total_value | Money | Value of the items in the delivery, sent in minor units. i.e.: $10.99 => 1099. |
 /// | external_store_id | string | (Optional) Unique identifier used by our Partners to reference a Store or Location |
 ///
 #[derive(Serialize, Default, Debug)]
 #[serde(rename_all = "snake_case")]
 pub struct CreateQuoteRequest {
-    pub pickup_address: String,
-    pub dropoff_address: String,
+    pub pickup_address: Address,
+    pub dropoff_address: Address,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dropoff_latitude: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -41,43 +44,75 @@ pub struct CreateQuoteRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pickup_phone_number: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pickup_ready_dt: Option<LocalDateTime>,
+    pub pickup_ready_dt: Option<Timestamp>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pickup_deadline_dt: Option<LocalDateTime>,
+    pub pickup_deadline_dt: Option<Timestamp>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub dropoff_ready_dt: Option<LocalDateTime>,
+    pub dropoff_ready_dt: Option<Timestamp>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub dropoff_deadline_dt: Option<LocalDateTime>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub manifest_total_value: Option<i64>,
+    pub dropoff_deadline_dt: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "minor_units::serialize")]
+    pub manifest_total_value: Option<Money>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub external_store_id: Option<String>,
 }
 impl CreateQuoteRequest {
-    pub fn new(
-        pickup_address: String, 
-        dropoff_address: String) -> Self {
+    pub fn new<A: Into<Address>>(
+        pickup_address: A, 
+        dropoff_address: A) -> Self {
         CreateQuoteRequest {
-            pickup_address: pickup_address,
-            dropoff_address: dropoff_address,
+            pickup_address: pickup_address.into(),
+            dropoff_address: dropoff_address.into(),
             ..Default::default()
         }
     }
 }
 
+/// The quote for a delivery, so its `quote_id` matches what gets booked
+impl From<&CreateDeliveryRequest> for CreateQuoteRequest {
+    fn from(request: &CreateDeliveryRequest) -> Self {
+        CreateQuoteRequest {
+            pickup_address: request.pickup_address.clone(),
+            dropoff_address: request.dropoff_address.clone(),
+            pickup_latitude: request.pickup_latitude,
+            pickup_longitude: request.pickup_longitude,
+            pickup_phone_number: Some(request.pickup_phone_number.clone()).filter(|phone| !phone.is_empty()),
+            dropoff_latitude: request.dropoff_latitude,
+            dropoff_longitude: request.dropoff_longitude,
+            dropoff_phone_number: Some(request.dropoff_phone_number.clone()).filter(|phone| !phone.is_empty()),
+            pickup_ready_dt: request.pickup_ready_dt,
+            pickup_deadline_dt: request.pickup_deadline_dt,
+            dropoff_ready_dt: request.dropoff_ready_dt,
+            dropoff_deadline_dt: request.dropoff_deadline_dt,
+            manifest_total_value: request.manifest_total_value.clone(),
+            external_store_id: request.external_store_id.clone(),
+        }
+    }
+}
+
 #[derive(Deserialize, Debug)]
-#[serde(rename_all = "snake_case")]
+#[serde(remote = "Self", rename_all = "snake_case")]
 pub struct CreateQuoteResponse {
-    pub created: Option<LocalDateTime>,
+    pub created: Option<Timestamp>,
     pub currency: Option<String>,
     pub currency_type: Option<String>,
-    pub dropoff_deadline: Option<LocalDateTime>,
-    pub dropoff_eta: Option<LocalDateTime>,
+    pub dropoff_deadline: Option<Timestamp>,
+    pub dropoff_eta: Option<Timestamp>,
     pub duration: Option<i64>,
-    pub expires: Option<LocalDateTime>,
-    pub fee: Option<i64>,
+    pub expires: Option<Timestamp>,
+    #[serde(default, deserialize_with = "minor_units::deserialize")]
+    pub fee: Option<Money>,
     pub id: Option<String>,
     pub kind: Option<String>,
     pub pickup_duration: Option<i64>,
     pub external_store_id: Option<String>,
+}
+
+// `fee` is read before `currency`, it's re-tagged once the whole quote is read
+impl<'de> Deserialize<'de> for CreateQuoteResponse {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut quote = CreateQuoteResponse::deserialize(deserializer)?;
+        quote.fee = minor_units::in_currency(quote.fee, quote.currency.as_deref());
+        Ok(quote)
+    }
 }
\ No newline at end of file
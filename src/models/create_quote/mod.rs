@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 mod create_quote;
 
 pub use create_quote::*;
\ No newline at end of file
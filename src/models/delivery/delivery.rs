@@ -0,0 +1,161 @@
+use serde::{Deserialize, Deserializer};
+
+use crate::models::delivery_state::DeliveryState;
+use crate::models::money::{minor_units, Money};
+use crate::models::general::{
+    CourierInfo,
+    Timestamp,
+    WaypointInfo,
+    ManifestInfo,
+    ManifestItem,
+    RelatedDelivery
+};
+
+/// # Delivery
+///
+/// The delivery object returned by create, get, update, cancel and list deliveries, and embedded as `data` in the webhooks.
+/// Fields only some of them send are noted below, everything is optional since Uber omits what doesn't apply yet.
+///
+/// |Name| Type| Description|
+/// | :--- | :--- | :--- |
+/// |id| string| Unique identifier for the delivery ( `del_` + tokenize(uuid)).|
+/// |status| DeliveryState| The current status of the delivery.|
+/// |complete| boolean |Flag indicating if the delivery is ongoing.|
+/// |courier| CourierInfo| Information about the courier. Only present when a delivery is in progress.|
+/// |courier_imminent| boolean |Flag indicating if the courier is close to the pickup or dropoff location.|
+/// |created| timestamp (RFC 3339)| Date/Time at which the delivery was created.|
+/// |updated| timestamp (RFC 3339)| Date/Time at which the delivery was last updated.|
+/// |currency| string| Three-letter ISO currency code, in lowercase.|
+/// |fee| Money| Amount that will be charged for this delivery, sent in cents and read in `currency`.|
+/// |tip| Money| Amount that will be paid to the courier as a tip, sent in cents and read in `currency`.|
+/// |deliverable_action| string| The "happy path" action for the courier. Not sent by create delivery.|
+/// |pickup| WaypointInfo| The pickup details for the delivery.|
+/// |pickup_ready| timestamp (RFC 3339)| When a delivery is ready to be picked up. This is the start of the pickup window.|
+/// |pickup_deadline| timestamp (RFC 3339)| When a delivery must be picked up by. This is the end of the pickup window.|
+/// |pickup_eta| timestamp (RFC 3339)| Estimated time the courier will arrive at the pickup location.|
+/// |dropoff| WaypointInfo| The dropoff details for the delivery.|
+/// |dropoff_ready| timestamp (RFC 3339)| When a delivery is ready to be dropped off. This is the start of the dropoff window.|
+/// |dropoff_deadline| timestamp (RFC 3339)| When a delivery must be dropped off. This is the end of the dropoff window.|
+/// |dropoff_eta| timestamp (RFC 3339)| Estimated drop-off time.|
+/// |dropoff_identifier| string| This field identifies who received delivery at the dropoff location.|
+/// |external_id| string| An ID for the delivery as stored in an external system.|
+/// |kind| string| The type of object being described. Always "delivery".|
+/// |live_mode| boolean| Flag that indicates if this is live mode or test mode.|
+/// |manifest| ManifestInfo| A detailed description of what the courier will be delivering.|
+/// |manifest_items| ManifestItem[]| List of items being delivered.|
+/// |quote_id| string| ID for the Delivery Quote if one was provided when creating this Delivery.|
+/// |related_deliveries| RelatedDelivery[]| A collection describing other jobs that share an association. i.e.: a return delivery. A single object is accepted too.|
+/// |tracking_url| string| This url can be used to track the courier during the delivery (unauthenticated page).|
+/// |undeliverable_action| string| If a delivery was undeliverable, this field will contain the resulting action taken by the courier.|
+/// |undeliverable_reason| string| If a delivery was undeliverable, this field will contain the reason why it was undeliverable.|
+/// |uuid| string| Alternative delivery identifier. Value is UUID v4 with '-' characters removed, case-insensitive.|
+/// |route_id| string| Defines an ID for a contiguous series of waypoints. Batched deliveries will have the same route. Webhooks only.|
+/// |order| OrderInfo| Order details for a delivery. Webhooks only.|
+/// |cancelation_reason| CancellationReason| Info on why the delivery was cancelled. Webhooks only.|
+/// |return| WaypointInfo| The return details for the delivery.|
+///
+#[derive(Deserialize, Debug, Clone)]
+#[serde(remote = "Self", rename_all = "snake_case")]
+pub struct Delivery {
+    pub id: Option<String>,
+    pub status: Option<DeliveryState>,
+    pub complete: Option<bool>,
+    pub courier: Option<CourierInfo>,
+    pub courier_imminent: Option<bool>,
+    pub created: Option<Timestamp>,
+    pub updated: Option<Timestamp>,
+    pub currency: Option<String>,
+    #[serde(default, deserialize_with = "minor_units::deserialize")]
+    pub fee: Option<Money>,
+    #[serde(default, deserialize_with = "minor_units::deserialize")]
+    pub tip: Option<Money>,
+    pub deliverable_action: Option<String>,
+    pub pickup: Option<WaypointInfo>,
+    pub pickup_ready: Option<Timestamp>,
+    pub pickup_deadline: Option<Timestamp>,
+    pub pickup_eta: Option<Timestamp>,
+    pub dropoff: Option<WaypointInfo>,
+    pub dropoff_ready: Option<Timestamp>,
+    pub dropoff_deadline: Option<Timestamp>,
+    pub dropoff_eta: Option<Timestamp>,
+    pub dropoff_identifier: Option<String>,
+    pub external_id: Option<String>,
+    pub kind: Option<String>,
+    pub live_mode: Option<bool>,
+    pub manifest: Option<ManifestInfo>,
+    pub manifest_items: Option<Vec<ManifestItem>>,
+    pub quote_id: Option<String>,
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub related_deliveries: Option<Vec<RelatedDelivery>>,
+    pub tracking_url: Option<String>,
+    pub undeliverable_action: Option<String>,
+    pub undeliverable_reason: Option<String>,
+    pub uuid: Option<String>,
+    pub route_id: Option<String>,
+    pub order: Option<OrderInfo>,
+    pub cancelation_reason: Option<CancellationReason>,
+    #[serde(rename = "return")]
+    pub return_waypoint: Option<WaypointInfo>,
+}
+
+// the derived impl reads the amounts before knowing `currency`, they're re-tagged once the whole delivery is read
+impl<'de> Deserialize<'de> for Delivery {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut delivery = Delivery::deserialize(deserializer)?;
+        delivery.fee = minor_units::in_currency(delivery.fee, delivery.currency.as_deref());
+        delivery.tip = minor_units::in_currency(delivery.tip, delivery.currency.as_deref());
+        if let Some(manifest) = &mut delivery.manifest {
+            manifest.total_value = minor_units::in_currency(manifest.total_value.take(), delivery.currency.as_deref());
+        }
+        Ok(delivery)
+    }
+}
+
+/// # Delivery - OrderInfo
+///
+/// |Name| Type| Description|
+/// | :--- | :--- | :--- |
+/// |id| string| Unique identifier for order|
+/// |number| string| A 3-digit customer and courier facing order id|
+/// |display_name| string| Customer name includes customer’s first name and last initial|
+///
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct OrderInfo {
+    pub id: Option<String>,
+    pub number: Option<String>,
+    pub display_name: Option<String>,
+}
+
+/// # Delivery - CancellationReason
+///
+/// |Name| Type| Description|
+/// | :--- | :--- | :--- |
+/// |primary_reason| string| |
+/// |secondary_reason| string| |
+///
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct CancellationReason {
+    pub primary_reason: Option<String>,
+    pub secondary_reason: Option<String>
+}
+
+// get and list deliveries used to be documented with a single related delivery
+fn one_or_many<'de, D>(deserializer: D) -> Result<Option<Vec<RelatedDelivery>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        Many(Vec<RelatedDelivery>),
+        One(RelatedDelivery),
+    }
+
+    Ok(match Option::<OneOrMany>::deserialize(deserializer)? {
+        Some(OneOrMany::Many(related)) => Some(related),
+        Some(OneOrMany::One(related)) => Some(vec![related]),
+        None => None,
+    })
+}
@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+mod delivery;
+
+pub use delivery::*;
\ No newline at end of file
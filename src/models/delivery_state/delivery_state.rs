@@ -0,0 +1,165 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::errors::UberError;
+
+/// # Delivery Status
+///
+/// The `status` of a delivery as reported by the delivery endpoints and the delivery status webhook, anything else is kept as `Unknown`.
+///
+/// | Status | Description |
+/// | :--- | :--- |
+/// | pending | Delivery is waiting to be assigned to a courier. |
+/// | pickup | Courier is assigned and is en route to pick up the items. |
+/// | pickup_complete | Courier has picked up the items and has begun the dropoff. |
+/// | dropoff | Courier is moving towards the dropoff. |
+/// | delivered | Courier has completed the dropoff. |
+/// | canceled | Delivery has been canceled. This could be an automatic internal cancelation due to no couriers being available or a manual cancelation. |
+/// | returned | The delivery was canceled and a new job created to return the items to the sender. |
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeliveryState {
+    Pending,
+    Pickup,
+    PickupComplete,
+    Dropoff,
+    Delivered,
+    Canceled,
+    Returned,
+    Unknown(String),
+}
+
+impl DeliveryState {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Pending => "pending",
+            Self::Pickup => "pickup",
+            Self::PickupComplete => "pickup_complete",
+            Self::Dropoff => "dropoff",
+            Self::Delivered => "delivered",
+            Self::Canceled => "canceled",
+            Self::Returned => "returned",
+            Self::Unknown(status) => status,
+        }
+    }
+
+    /// The delivery won't change state again
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Delivered | Self::Canceled | Self::Returned)
+    }
+
+    /// The delivery is still in progress, the same deliveries as the list_deliveries `ongoing` filter
+    pub fn is_active(&self) -> bool {
+        matches!(self, Self::Pending | Self::Pickup | Self::PickupComplete | Self::Dropoff)
+    }
+
+    /// A courier has the items
+    pub fn is_picked_up(&self) -> bool {
+        matches!(self, Self::PickupComplete | Self::Dropoff | Self::Delivered | Self::Returned)
+    }
+
+    /// Position along pending -> pickup -> pickup_complete -> dropoff -> delivered
+    fn progress(&self) -> Option<u8> {
+        match self {
+            Self::Pending => Some(0),
+            Self::Pickup => Some(1),
+            Self::PickupComplete => Some(2),
+            Self::Dropoff => Some(3),
+            Self::Delivered => Some(4),
+            _ => None,
+        }
+    }
+
+    /// Whether a delivery observed as `self` can later be observed as `next`
+    ///
+    /// Observations may skip states (a missed webhook, polling), so any forward move along the lifecycle is allowed, as is a courier dropping the job
+    /// before pickup (pickup -> pending). Nothing leaves a terminal state, and `returned` is only reachable once the items were picked up.
+    /// Transitions involving an `Unknown` status can't be judged and are allowed.
+    pub fn can_transition_to(&self, next: &DeliveryState) -> bool {
+        if self == next {
+            return true;
+        }
+        if matches!(self, Self::Unknown(_)) || matches!(next, Self::Unknown(_)) {
+            return true;
+        }
+        if self.is_terminal() {
+            return false;
+        }
+
+        match next {
+            Self::Canceled => true,
+            Self::Returned => self.is_picked_up(),
+            Self::Pending => *self == Self::Pickup,
+            _ => self.progress() < next.progress(),
+        }
+    }
+
+    /// `UberError::InvalidState` when `next` can't follow `self`, see [`DeliveryState::can_transition_to`]
+    pub fn validate_transition(&self, next: &DeliveryState) -> Result<(), UberError> {
+        if self.can_transition_to(next) {
+            return Ok(());
+        }
+
+        Err(UberError::InvalidState(format!(
+            "delivery can't go from {} to {}",
+            self, next
+        )))
+    }
+}
+
+impl From<&str> for DeliveryState {
+    fn from(status: &str) -> Self {
+        match status {
+            "pending" => Self::Pending,
+            "pickup" => Self::Pickup,
+            "pickup_complete" => Self::PickupComplete,
+            "dropoff" => Self::Dropoff,
+            "delivered" => Self::Delivered,
+            "canceled" => Self::Canceled,
+            "returned" => Self::Returned,
+            other => Self::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl From<String> for DeliveryState {
+    fn from(status: String) -> Self {
+        Self::from(status.as_str())
+    }
+}
+
+impl FromStr for DeliveryState {
+    type Err = Infallible;
+
+    fn from_str(status: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(status))
+    }
+}
+
+impl fmt::Display for DeliveryState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for DeliveryState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for DeliveryState {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let status = String::deserialize(deserializer)?;
+        Ok(Self::from(status))
+    }
+}
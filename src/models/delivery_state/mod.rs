@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+mod delivery_state;
+
+pub use delivery_state::*;
\ No newline at end of file
@@ -0,0 +1,197 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::errors::{UberError, UberErrorCode};
+use crate::models::create_delivery::CreateDeliveryRequest;
+use crate::models::create_quote::CreateQuoteRequest;
+use crate::models::general::Timestamp;
+
+/// Source of the current time for [`DeliveryWindows`], so checks can be pinned to a fixed instant in tests or replays
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The system clock
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock stuck at one instant
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+/// # Pickup and Dropoff Windows
+///
+/// The `*_dt` fields of a `CreateQuoteRequest` or `CreateDeliveryRequest`, checked against the rules from the docs.
+/// Each violation maps to the error code Uber would answer with.
+///
+/// | Rule | Code |
+/// | :--- | :--- |
+/// | pickup_ready_dt is set whenever any other window time is | pickup_ready_time_not_specified |
+/// | pickup_ready_dt is not in the past, by more than a minute | pickup_ready_too_early |
+/// | pickup_ready_dt is less than 30 days in the future | pickup_ready_too_late |
+/// | pickup_deadline_dt is at least 10 mins later than pickup_ready_dt | pickup_window_too_small |
+/// | pickup_deadline_dt is at least 20 minutes in the future | pickup_deadline_too_early |
+/// | dropoff_ready_dt is less than or equal to pickup_deadline_dt | dropoff_ready_after_pickup_deadline |
+/// | dropoff_deadline_dt is at least 20 mins later than dropoff_ready_dt | dropoff_deadline_too_early |
+/// | dropoff_deadline_dt is greater than or equal to pickup_deadline_dt | dropoff_deadline_before_pickup_deadline |
+///
+/// Example:
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use uber_api::{DeliveryWindows, FixedClock, UberErrorCode};
+///
+/// let now = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+/// let windows = DeliveryWindows {
+///     pickup_ready: Some(now + Duration::minutes(5)),
+///     pickup_deadline: Some(now + Duration::minutes(10)),
+///     ..Default::default()
+/// };
+///
+/// let err = windows.validate_with(&FixedClock(now)).unwrap_err();
+/// assert_eq!(err.error_code(), Some(&UberErrorCode::PickupWindowTooSmall));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DeliveryWindows {
+    pub pickup_ready: Option<DateTime<Utc>>,
+    pub pickup_deadline: Option<DateTime<Utc>>,
+    pub dropoff_ready: Option<DateTime<Utc>>,
+    pub dropoff_deadline: Option<DateTime<Utc>>,
+}
+
+impl DeliveryWindows {
+    /// How far in the past pickup_ready may be, so a "ready now" time built a moment before validation still passes
+    pub const PICKUP_READY_GRACE: Duration = Duration::minutes(1);
+    pub const MAX_PICKUP_READY_AHEAD: Duration = Duration::days(30);
+    pub const MIN_PICKUP_WINDOW: Duration = Duration::minutes(10);
+    pub const MIN_PICKUP_DEADLINE_AHEAD: Duration = Duration::minutes(20);
+    pub const MIN_DROPOFF_WINDOW: Duration = Duration::minutes(20);
+
+    fn from_timestamps(
+        pickup_ready: Option<Timestamp>,
+        pickup_deadline: Option<Timestamp>,
+        dropoff_ready: Option<Timestamp>,
+        dropoff_deadline: Option<Timestamp>,
+    ) -> Self {
+        DeliveryWindows {
+            pickup_ready: pickup_ready.map(DateTime::from),
+            pickup_deadline: pickup_deadline.map(DateTime::from),
+            dropoff_ready: dropoff_ready.map(DateTime::from),
+            dropoff_deadline: dropoff_deadline.map(DateTime::from),
+        }
+    }
+
+    /// Every rule the windows break at `clock.now()`, in the order of the table above
+    pub fn violations(&self, clock: &impl Clock) -> Vec<UberErrorCode> {
+        let now = clock.now();
+        let mut violations = Vec::new();
+
+        let any_window = self.pickup_deadline.is_some() || self.dropoff_ready.is_some() || self.dropoff_deadline.is_some();
+        if self.pickup_ready.is_none() && any_window {
+            violations.push(UberErrorCode::PickupReadyTimeNotSpecified);
+        }
+
+        if let Some(pickup_ready) = self.pickup_ready {
+            if pickup_ready < now - Self::PICKUP_READY_GRACE {
+                violations.push(UberErrorCode::PickupReadyTooEarly);
+            }
+            if pickup_ready >= now + Self::MAX_PICKUP_READY_AHEAD {
+                violations.push(UberErrorCode::PickupReadyTooLate);
+            }
+        }
+
+        if let Some(pickup_deadline) = self.pickup_deadline {
+            if let Some(pickup_ready) = self.pickup_ready {
+                if pickup_deadline - pickup_ready < Self::MIN_PICKUP_WINDOW {
+                    violations.push(UberErrorCode::PickupWindowTooSmall);
+                }
+            }
+            if pickup_deadline < now + Self::MIN_PICKUP_DEADLINE_AHEAD {
+                violations.push(UberErrorCode::PickupDeadlineTooEarly);
+            }
+        }
+
+        if let (Some(dropoff_ready), Some(pickup_deadline)) = (self.dropoff_ready, self.pickup_deadline) {
+            if dropoff_ready > pickup_deadline {
+                violations.push(UberErrorCode::DropoffReadyAfterPickupDeadline);
+            }
+        }
+
+        if let Some(dropoff_deadline) = self.dropoff_deadline {
+            if let Some(dropoff_ready) = self.dropoff_ready {
+                if dropoff_deadline - dropoff_ready < Self::MIN_DROPOFF_WINDOW {
+                    violations.push(UberErrorCode::DropoffDeadlineTooEarly);
+                }
+            }
+            if let Some(pickup_deadline) = self.pickup_deadline {
+                if dropoff_deadline < pickup_deadline {
+                    violations.push(UberErrorCode::DropoffDeadlineBeforePickupDeadline);
+                }
+            }
+        }
+
+        violations
+    }
+
+    /// `UberError::Validation` carrying the code of the first broken rule at `clock.now()`
+    pub fn validate_with(&self, clock: &impl Clock) -> Result<(), UberError> {
+        match self.violations(clock).into_iter().next() {
+            Some(code) => Err(UberError::Validation {
+                message: code.description().unwrap_or_default().to_string(),
+                code,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Check the windows against the system clock
+    pub fn validate(&self) -> Result<(), UberError> {
+        self.validate_with(&SystemClock)
+    }
+}
+
+impl From<&CreateQuoteRequest> for DeliveryWindows {
+    fn from(request: &CreateQuoteRequest) -> Self {
+        DeliveryWindows::from_timestamps(
+            request.pickup_ready_dt,
+            request.pickup_deadline_dt,
+            request.dropoff_ready_dt,
+            request.dropoff_deadline_dt,
+        )
+    }
+}
+
+impl From<&CreateDeliveryRequest> for DeliveryWindows {
+    fn from(request: &CreateDeliveryRequest) -> Self {
+        DeliveryWindows::from_timestamps(
+            request.pickup_ready_dt,
+            request.pickup_deadline_dt,
+            request.dropoff_ready_dt,
+            request.dropoff_deadline_dt,
+        )
+    }
+}
+
+impl CreateQuoteRequest {
+    /// Check the pickup/dropoff windows against `clock`, see [`DeliveryWindows`]
+    pub fn validate_windows(&self, clock: &impl Clock) -> Result<(), UberError> {
+        DeliveryWindows::from(self).validate_with(clock)
+    }
+}
+
+impl CreateDeliveryRequest {
+    /// Check the pickup/dropoff windows against `clock`, see [`DeliveryWindows`]
+    pub fn validate_windows(&self, clock: &impl Clock) -> Result<(), UberError> {
+        DeliveryWindows::from(self).validate_with(clock)
+    }
+}
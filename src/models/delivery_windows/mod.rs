@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+mod delivery_windows;
+
+pub use delivery_windows::*;
\ No newline at end of file
@@ -1,64 +1,122 @@
 use serde::{Serialize, Deserialize, Serializer, Deserializer};
-use chrono::{DateTime, Local, NaiveDateTime, TimeZone};
+use chrono::{DateTime, FixedOffset, Local, SecondsFormat, TimeZone, Utc};
 use std::fmt;
+use std::str::FromStr;
+
+use crate::models::money::{minor_units, Money};
+
+/// # Timestamp
+///
+/// An instant in time as Uber sends and expects it, RFC 3339.
+///
+/// Serializes as UTC with a `Z` suffix, e.g. `2023-02-09T09:29:27Z` (fractional seconds are kept when present).
+/// Deserializing accepts fractional seconds and any offset, e.g. `2023-02-09T11:29:27.52+02:00`, and malformed input is a serde error rather than a panic.
+///
+/// Example:
+/// ```
+/// use chrono::FixedOffset;
+/// use uber_api::models::general::Timestamp;
+///
+/// let timestamp: Timestamp = "2023-02-09T11:29:27+02:00".parse().unwrap();
+/// assert_eq!(timestamp.to_string(), "2023-02-09T09:29:27Z");
+///
+/// // store-local time for a store two hours ahead of UTC
+/// let store_time = timestamp.to_timezone(&FixedOffset::east_opt(2 * 3600).unwrap());
+/// assert_eq!(store_time.to_rfc3339(), "2023-02-09T11:29:27+02:00");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp(DateTime<Utc>);
+
+/// Kept so existing code keeps compiling, `LocalDateTime` labelled local times as UTC and panicked on malformed input
+#[deprecated(note = "use Timestamp")]
+pub type LocalDateTime = Timestamp;
+
+impl Timestamp {
+    pub fn now() -> Self {
+        Timestamp(Utc::now())
+    }
+
+    pub fn as_utc(&self) -> DateTime<Utc> {
+        self.0
+    }
 
-#[derive(Clone)]
-pub struct LocalDateTime(DateTime<Local>);
+    /// The same instant in a given timezone, e.g. the store's local time
+    pub fn to_timezone<Tz: TimeZone>(&self, timezone: &Tz) -> DateTime<Tz> {
+        self.0.with_timezone(timezone)
+    }
+}
 
-impl Serialize for LocalDateTime {
+impl Serialize for Timestamp {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         where
             S: Serializer,
     {
-        serializer.collect_str(&format!("{}", self.0.format("%Y-%m-%dT%H:%M:%SZ"))) // 2019-10-12T07:20:50.52Z
+        serializer.collect_str(self)
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0.to_rfc3339_opts(SecondsFormat::AutoSi, true))
     }
-}// 2023-02-09T09:29:27Z
+}
+
+impl FromStr for Timestamp {
+    type Err = chrono::ParseError;
 
-impl ToString for LocalDateTime {
-    fn to_string(&self) -> String {
-        format!("{}", self.0.format("%Y-%m-%dT%H:%M:%SZ"))
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let datetime = DateTime::parse_from_rfc3339(value.trim())?;
+        Ok(Timestamp(datetime.with_timezone(&Utc)))
     }
 }
 
-impl<'de> Deserialize<'de> for LocalDateTime {
+impl<'de> Deserialize<'de> for Timestamp {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
         let s = String::deserialize(deserializer)?;
-        let dt = DateTime::parse_from_rfc3339(&s).unwrap();
-        // let dt = Local.datetime_from_str(&s, "%YYYY-%mm-%ddT%H:%M:%SZ").map_err(D::Error::custom)?;
-        Ok(LocalDateTime(dt.into()))
+        s.parse().map_err(|err| serde::de::Error::custom(format!("invalid RFC 3339 timestamp '{}' - {}", s, err)))
     }
 }
 
-impl fmt::Debug for LocalDateTime {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "LocalDateTime({:?})", self.0)
+impl From<DateTime<Utc>> for Timestamp {
+    fn from(value: DateTime<Utc>) -> Self {
+        Self(value)
     }
 }
 
-impl Default for LocalDateTime {
-    fn default() -> Self {
-        let default_datetime = NaiveDateTime::from_timestamp_opt(0, 0).unwrap_or_default();
-        let local_datetime = Local.from_local_datetime(&default_datetime).unwrap();
-        LocalDateTime(local_datetime)
+impl From<DateTime<FixedOffset>> for Timestamp {
+    fn from(value: DateTime<FixedOffset>) -> Self {
+        Self(value.with_timezone(&Utc))
     }
 }
 
-impl From<DateTime<Local>> for LocalDateTime {
+impl From<DateTime<Local>> for Timestamp {
     fn from(value: DateTime<Local>) -> Self {
-        Self(value)
+        Self(value.with_timezone(&Utc))
     }
 }
 
-impl From<LocalDateTime> for DateTime<Local> {
-    fn from(value: LocalDateTime) -> Self {
+impl From<Timestamp> for DateTime<Utc> {
+    fn from(value: Timestamp) -> Self {
         value.0
     }
 }
 
-#[derive(Deserialize, Debug)]
+impl From<Timestamp> for DateTime<FixedOffset> {
+    fn from(value: Timestamp) -> Self {
+        value.0.fixed_offset()
+    }
+}
+
+impl From<Timestamp> for DateTime<Local> {
+    fn from(value: Timestamp) -> Self {
+        value.0.with_timezone(&Local)
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
 pub struct CourierInfo {
     pub name: Option<String>,
     pub vehicle_type: Option<String>,
@@ -67,41 +125,43 @@ pub struct CourierInfo {
     pub img_href: Option<String>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct LatLng {
     pub lat: f64,
     pub lng: f64,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
 pub struct RelatedDelivery {
     pub id: String,
     pub relationship: String,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
 pub struct ManifestInfo {
     pub reference: Option<String>,
     pub description: String,
-    pub total_value: u32,
+    /// Read in the delivery's `currency`
+    #[serde(default, deserialize_with = "minor_units::deserialize")]
+    pub total_value: Option<Money>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
 pub struct WaypointInfo {
-    pub name: String,
-    pub phone_number: String,
-    pub address: String,
-    pub detailed_address: StructuredAddressResponse,
+    pub name: Option<String>,
+    pub phone_number: Option<String>,
+    pub address: Option<String>,
+    pub detailed_address: Option<StructuredAddressResponse>,
     pub notes: Option<String>,
     pub seller_notes: Option<String>,
     pub courier_notes: Option<String>,
-    pub location: LatLng,	
+    pub location: Option<LatLng>,
     pub verification: Option<VerificationProof>,
     pub verification_requirements: Option<VerificationRequirement>,
     pub external_store_id: Option<String>,
 }
 
-#[derive(Deserialize, Debug, Serialize, Default, Clone)]
+#[derive(Deserialize, Debug, Serialize, Default, Clone, PartialEq, Eq)]
 pub struct StructuredAddress { 
     pub street_address: Vec<String>, // vec containing street_address_1, street_address_2 (optional), sublocality_level_1 (optional)
     pub city: String,
@@ -123,8 +183,166 @@ pub struct StructuredAddressResponse {
     pub sublocality_level_1: Option<String>,
 }
 
+impl From<StructuredAddressResponse> for StructuredAddress {
+    fn from(value: StructuredAddressResponse) -> Self {
+        StructuredAddress {
+            street_address: [value.street_address_1, value.street_address_2, value.sublocality_level_1]
+                .into_iter()
+                .flatten()
+                .filter(|line| !line.is_empty())
+                .collect(),
+            city: value.city.unwrap_or_default(),
+            state: value.state.unwrap_or_default(),
+            zip_code: value.zip_code.unwrap_or_default(),
+            country: value.country,
+        }
+    }
+}
+
+/// # Address
+///
+/// A pickup or dropoff address, either a single free-form line ("Street Address, City, State, Zip") or a `StructuredAddress`.
+///
+/// Uber expects structured addresses as a JSON object encoded inside the string field, which `Address` does when serializing,
+/// so a `StructuredAddress` can be passed as is. Deserializing accepts the free-form line, the JSON-in-a-string format,
+/// and the structured object from responses (`StructuredAddressResponse`).
+///
+/// Example:
+/// ```
+/// use uber_api::models::general::{Address, StructuredAddress};
+///
+/// let address = Address::from(StructuredAddress {
+///     street_address: vec!["123 Main St".to_string()],
+///     city: "San Francisco".to_string(),
+///     state: "CA".to_string(),
+///     zip_code: "94103".to_string(),
+///     country: Some("US".to_string()),
+/// });
+///
+/// assert_eq!(
+///     serde_json::to_string(&address).unwrap(),
+///     r#""{\"street_address\":[\"123 Main St\"],\"city\":\"San Francisco\",\"state\":\"CA\",\"zip_code\":\"94103\",\"country\":\"US\"}""#
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Address {
+    FreeForm(String),
+    Structured(StructuredAddress),
+}
+
+impl Address {
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::FreeForm(address) => address.trim().is_empty(),
+            Self::Structured(address) => address.street_address.is_empty() && address.city.is_empty() && address.zip_code.is_empty(),
+        }
+    }
+
+    /// The string Uber expects in the address field
+    pub fn to_request_string(&self) -> String {
+        match self {
+            Self::FreeForm(address) => address.clone(),
+            Self::Structured(address) => serde_json::to_string(address).unwrap_or_default(),
+        }
+    }
+
+    /// Parse an address field, a JSON encoded structured address (in the request or response shape) or else a free-form line
+    pub fn parse(address: &str) -> Self {
+        let trimmed = address.trim_start();
+        if trimmed.starts_with('{') {
+            if let Ok(value) = serde_json::from_str::<serde_json::Value>(trimmed) {
+                if let Some(structured) = Self::from_object(value) {
+                    return structured;
+                }
+            }
+        }
+
+        Self::FreeForm(address.to_string())
+    }
+
+    fn from_object(value: serde_json::Value) -> Option<Self> {
+        if value.get("street_address").is_some() {
+            return serde_json::from_value::<StructuredAddress>(value).ok().map(Self::Structured);
+        }
+        serde_json::from_value::<StructuredAddressResponse>(value)
+            .ok()
+            .map(|address| Self::Structured(address.into()))
+    }
+}
 
-#[derive(Deserialize, Debug)]
+impl Default for Address {
+    fn default() -> Self {
+        Self::FreeForm(String::new())
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_request_string())
+    }
+}
+
+impl From<&str> for Address {
+    fn from(address: &str) -> Self {
+        Self::FreeForm(address.to_string())
+    }
+}
+
+impl From<String> for Address {
+    fn from(address: String) -> Self {
+        Self::FreeForm(address)
+    }
+}
+
+impl From<&String> for Address {
+    fn from(address: &String) -> Self {
+        Self::FreeForm(address.clone())
+    }
+}
+
+impl From<StructuredAddress> for Address {
+    fn from(address: StructuredAddress) -> Self {
+        Self::Structured(address)
+    }
+}
+
+impl From<StructuredAddressResponse> for Address {
+    fn from(address: StructuredAddressResponse) -> Self {
+        Self::Structured(address.into())
+    }
+}
+
+impl Serialize for Address {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::FreeForm(address) => serializer.serialize_str(address),
+            Self::Structured(address) => {
+                let encoded = serde_json::to_string(address).map_err(serde::ser::Error::custom)?;
+                serializer.serialize_str(&encoded)
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Address {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match serde_json::Value::deserialize(deserializer)? {
+            serde_json::Value::String(address) => Ok(Self::parse(&address)),
+            value @ serde_json::Value::Object(_) => Self::from_object(value)
+                .ok_or_else(|| serde::de::Error::custom("address object is not a structured address")),
+            other => Err(serde::de::Error::custom(format!("expected an address string or object, found {}", other))),
+        }
+    }
+}
+
+
+#[derive(Deserialize, Debug, Clone)]
 pub struct VerificationProof {
     pub signature: Option<SignatureProof>,
     pub barcodes: Option<Vec<BarcodeRequirement>>,
@@ -133,24 +351,24 @@ pub struct VerificationProof {
     pub pin_code: Option<PincodeProof>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
 pub struct SignatureProof {
     pub image_url: Option<String>,
     pub signer_name: Option<String>,
     pub signer_relationship: Option<String>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
 pub struct PictureProof {
     pub image_url: Option<String>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
 pub struct IdentificationProof {
     pub min_age_verified: Option<bool>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
 pub struct PincodeProof {
     pub entered: Option<String>,
 }
@@ -163,8 +381,8 @@ pub struct ManifestItem {
     pub size: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dimensions: Option<Dimensions>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub price: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "minor_units")]
+    pub price: Option<Money>,
     #[serde(skip_serializing_if = "should_skip_serialization_bool")]
     pub must_be_upright: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -190,7 +408,7 @@ impl ManifestItem {
     }
 }
 
-#[derive(Serialize, Debug, Deserialize, Default)]
+#[derive(Serialize, Debug, Deserialize, Default, Clone)]
 pub struct VerificationRequirement {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub signature_requirement: Option<SignatureRequirement>,
@@ -213,7 +431,7 @@ pub struct Dimensions {
     pub depth: Option<u32>,
 }
 
-#[derive(Serialize, Debug, Deserialize)]
+#[derive(Serialize, Debug, Deserialize, Clone)]
 pub struct SignatureRequirement {
     pub enabled: bool,
     pub collect_signer_name: bool,
@@ -228,19 +446,19 @@ pub struct BarcodeRequirement {
     pub type_of_barcode: String,
 }
 
-#[derive(Serialize, Debug, Deserialize)]
+#[derive(Serialize, Debug, Deserialize, Clone)]
 pub struct PincodeRequirement {
     pub enabled: bool,
     pub value: String,
 }
 
-#[derive(Serialize, Debug, Deserialize)]
+#[derive(Serialize, Debug, Deserialize, Clone)]
 pub struct PackageRequirement {
     pub bag_count: u32,
     pub drink_count: u32,
 }
 
-#[derive(Serialize, Debug, Deserialize)]
+#[derive(Serialize, Debug, Deserialize, Clone)]
 pub struct IdentificationRequirement {
     pub min_age: u32,
 }
@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 mod general;
 
 pub use general::*;
\ No newline at end of file
@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 mod get_delivery;
 
 pub use get_delivery::*;
\ No newline at end of file
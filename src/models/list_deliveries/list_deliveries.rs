@@ -1,13 +1,122 @@
-use serde::{Deserialize};
+use std::fmt;
+use std::str::FromStr;
 
-use crate::models::general::{
-    CourierInfo,
-    LocalDateTime,
-    WaypointInfo,
-    ManifestInfo,
-    ManifestItem,
-    RelatedDelivery
-};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::UberError;
+
+use crate::models::delivery::Delivery;
+
+/// Delivery state accepted by the list_deliveries `filter` query parameter
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryFilter {
+    Pending,
+    Pickup,
+    PickupComplete,
+    Dropoff,
+    Delivered,
+    Canceled,
+    Returned,
+    /// Any delivery that hasn't reached a final state yet
+    Ongoing,
+}
+
+impl DeliveryFilter {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Pickup => "pickup",
+            Self::PickupComplete => "pickup_complete",
+            Self::Dropoff => "dropoff",
+            Self::Delivered => "delivered",
+            Self::Canceled => "canceled",
+            Self::Returned => "returned",
+            Self::Ongoing => "ongoing",
+        }
+    }
+}
+
+impl fmt::Display for DeliveryFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for DeliveryFilter {
+    type Err = UberError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "pending" => Ok(Self::Pending),
+            "pickup" => Ok(Self::Pickup),
+            "pickup_complete" => Ok(Self::PickupComplete),
+            "dropoff" => Ok(Self::Dropoff),
+            "delivered" => Ok(Self::Delivered),
+            "canceled" => Ok(Self::Canceled),
+            "returned" => Ok(Self::Returned),
+            "ongoing" => Ok(Self::Ongoing),
+            _ => Err(UberError::BadInput(format!("filter: unknown delivery filter '{}'", value))),
+        }
+    }
+}
+
+/// # Query Parameters
+///
+/// | Name | Type | Description |
+/// | :--- | :--- | :--- |
+/// | filter | DeliveryFilter | Filter deliveries by delivery state. |
+/// | limit | integer | Maximum number of responses to return, must be at least 1. |
+/// | offset | integer | Offset of response objects for pagination, only valid together with limit. |
+///
+#[derive(Serialize, Default, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct ListDeliveriesQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<DeliveryFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+}
+impl ListDeliveriesQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_filter(mut self, filter: DeliveryFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn with_offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Reject combinations Uber would refuse or silently misread, before the request goes out
+    pub fn validate(&self) -> Result<(), UberError> {
+        if self.limit == Some(0) {
+            return Err(UberError::BadInput("limit: must be at least 1".to_string()));
+        }
+        if self.offset.is_some() && self.limit.is_none() {
+            return Err(UberError::BadInput("offset: requires limit to be set".to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// Validated, url encoded query string without the leading `?`, empty when nothing is set
+    pub fn to_query_string(&self) -> Result<String, UberError> {
+        self.validate()?;
+        Ok(serde_urlencoded::to_string(self)?)
+    }
+}
 
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "snake_case")]
@@ -19,39 +128,3 @@ pub struct ListDeliveriesResponse {
     pub url: Option<String>,
 }
 
-#[derive(Deserialize, Debug)]
-#[serde(rename_all = "snake_case")]
-pub struct Delivery {
-    pub complete: Option<bool>,
-    pub courier: Option<CourierInfo>,
-    pub courier_imminent: Option<bool>,
-    pub created: Option<LocalDateTime>,
-    pub currency: Option<String>,
-    pub deliverable_action: Option<String>,
-    pub dropoff: Option<WaypointInfo>,
-    pub dropoff_deadline: Option<LocalDateTime>,
-    pub dropoff_eta: Option<LocalDateTime>,
-    pub dropoff_identifier: Option<String>,
-    pub dropoff_ready: Option<LocalDateTime>,
-    pub fee: Option<u32>,
-    pub id: Option<String>,
-    pub kind: Option<String>,
-    pub live_mode: Option<bool>,
-    pub manifest: Option<ManifestInfo>,
-    pub manifest_items:	Option<Vec<ManifestItem>>,
-    pub pickup:	Option<WaypointInfo>,
-    pub pickup_deadline: Option<LocalDateTime>,
-    pub pickup_eta: Option<LocalDateTime>,
-    pub pickup_ready: Option<LocalDateTime>,
-    pub quote_id: Option<String>,
-    pub related_deliveries: Option<RelatedDelivery>,
-    pub status: Option<String>,
-    pub tip: Option<u32>,
-    pub tracking_url: Option<String>,
-    pub undeliverable_action: Option<String>,
-    pub undeliverable_reason: Option<String>,
-    pub updated: Option<LocalDateTime>,
-    pub uuid: Option<String>,
-    #[serde(rename = "return")]
-    pub return_waypoint: Option<WaypointInfo>,
-}
@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 mod list_deliveries;
 
 pub use list_deliveries::*;
\ No newline at end of file
@@ -5,6 +5,10 @@ pub mod get_delivery;
 pub mod update_delivery;
 pub mod cancel_delivery;
 pub mod list_deliveries;
+pub mod delivery;
+pub mod delivery_state;
+pub mod delivery_windows;
+pub mod money;
 pub mod menu;
 pub mod order;
 pub mod pod_retrieval;
@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+mod money;
+
+pub use money::*;
\ No newline at end of file
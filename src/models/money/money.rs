@@ -0,0 +1,312 @@
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::errors::UberError;
+
+/// Currency assumed for bare cents amounts when Uber doesn't send a `currency` alongside them
+pub const DEFAULT_CURRENCY: &str = "USD";
+
+/// # Money
+///
+/// An amount in the minor units of its currency (cents for USD, yen for JPY) with the ISO 4217 currency code.
+///
+/// On the wire it has the Eats shape below. The Direct endpoints send bare integers instead: their `fee`, `tip` and value fields are
+/// typed `Money` too and go through [`minor_units`], which reads them in the response's `currency`.
+///
+/// | Name | Type | Description |
+/// | :--- | :--- | :--- |
+/// | amount | integer | Amount in minor units of the currency, i.e.: $10.99=>1099. |
+/// | currency_code | string | ISO 4217 currency code. |
+/// | formatted_amount | string | Amount formatted for display. Read-only, recomputed with [`Money::format`]. |
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Money {
+    amount: i64,
+    currency: String,
+}
+
+impl Money {
+    /// The currency code is upper-cased, Uber sends `usd` on deliveries and `USD` on orders
+    pub fn new(amount: i64, currency: &str) -> Self {
+        Money {
+            amount,
+            currency: currency.trim().to_ascii_uppercase(),
+        }
+    }
+
+    pub fn zero(currency: &str) -> Self {
+        Money::new(0, currency)
+    }
+
+    /// A bare cents amount from a Direct response, in `currency` or [`DEFAULT_CURRENCY`] if it's missing
+    pub fn from_minor_units(amount: Option<i64>, currency: Option<&str>) -> Option<Self> {
+        amount.map(|amount| Money::new(amount, currency.unwrap_or(DEFAULT_CURRENCY)))
+    }
+
+    pub fn amount(&self) -> i64 {
+        self.amount
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.amount == 0
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.amount < 0
+    }
+
+    /// Number of decimal places the currency's minor unit represents
+    pub fn exponent(&self) -> u32 {
+        currency_exponent(&self.currency)
+    }
+
+    pub fn checked_add(&self, other: &Money) -> Result<Money, UberError> {
+        self.same_currency(other)?;
+        self.amount
+            .checked_add(other.amount)
+            .map(|amount| Money::new(amount, &self.currency))
+            .ok_or_else(|| overflow(self, "+", other))
+    }
+
+    pub fn checked_sub(&self, other: &Money) -> Result<Money, UberError> {
+        self.same_currency(other)?;
+        self.amount
+            .checked_sub(other.amount)
+            .map(|amount| Money::new(amount, &self.currency))
+            .ok_or_else(|| overflow(self, "-", other))
+    }
+
+    pub fn checked_mul(&self, quantity: i64) -> Result<Money, UberError> {
+        self.amount
+            .checked_mul(quantity)
+            .map(|amount| Money::new(amount, &self.currency))
+            .ok_or_else(|| UberError::InvalidState(format!("{} * {} overflows", self, quantity)))
+    }
+
+    /// Sum of `amounts`, all of which must be in `currency`
+    pub fn checked_sum<'a, I: IntoIterator<Item = &'a Money>>(currency: &str, amounts: I) -> Result<Money, UberError> {
+        amounts.into_iter().try_fold(Money::zero(currency), |total, amount| total.checked_add(amount))
+    }
+
+    /// Fails with `UberError::CurrencyMismatch` unless both amounts are in the same currency
+    pub fn same_currency(&self, other: &Money) -> Result<(), UberError> {
+        if self.currency == other.currency {
+            Ok(())
+        } else {
+            Err(UberError::CurrencyMismatch {
+                expected: self.currency.clone(),
+                found: other.currency.clone(),
+            })
+        }
+    }
+
+    /// The amount in major units without a symbol, e.g. `10.99`
+    pub fn to_decimal_string(&self) -> String {
+        let (units, fraction) = self.split_units();
+        let sign = if self.is_negative() { "-" } else { "" };
+        match fraction {
+            Some(fraction) => format!("{}{}.{}", sign, units, fraction),
+            None => format!("{}{}", sign, units),
+        }
+    }
+
+    /// Format for display in a BCP 47 locale such as `en-US`, `de-DE` or `fr-FR`, e.g. `$1,234.50`, `1.234,50 €` or `1 234,50 €`.
+    /// Unknown locales are formatted like `en-US`, currencies without a known symbol use their code.
+    pub fn format(&self, locale: &str) -> String {
+        let style = LocaleStyle::for_locale(locale);
+        let (units, fraction) = self.split_units();
+
+        let mut number = group_digits(&units, style.group_separator);
+        if let Some(fraction) = fraction {
+            number.push(style.decimal_separator);
+            number.push_str(&fraction);
+        }
+
+        let symbol = currency_symbol(&self.currency).unwrap_or(&self.currency);
+        let sign = if self.is_negative() { "-" } else { "" };
+
+        if style.symbol_first {
+            // codes always get a space, e.g. "CHF 10.00"
+            let space = if style.symbol_spaced || symbol == self.currency { "\u{a0}" } else { "" };
+            format!("{}{}{}{}", sign, symbol, space, number)
+        } else {
+            format!("{}{}\u{a0}{}", sign, number, symbol)
+        }
+    }
+
+    fn split_units(&self) -> (String, Option<String>) {
+        let exponent = self.exponent();
+        let magnitude = self.amount.unsigned_abs();
+        if exponent == 0 {
+            return (magnitude.to_string(), None);
+        }
+
+        let divisor = 10u64.pow(exponent);
+        (
+            (magnitude / divisor).to_string(),
+            Some(format!("{:0width$}", magnitude % divisor, width = exponent as usize)),
+        )
+    }
+}
+
+/// e.g. `10.99 USD`
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.to_decimal_string(), self.currency)
+    }
+}
+
+impl Default for Money {
+    fn default() -> Self {
+        Money::zero(DEFAULT_CURRENCY)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct MoneyObject {
+    // required, a missing amount isn't a zero one
+    amount: i64,
+    #[serde(default)]
+    currency_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    formatted_amount: Option<String>,
+}
+
+impl Serialize for Money {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MoneyObject {
+            amount: self.amount,
+            currency_code: Some(self.currency.clone()),
+            formatted_amount: Some(self.format("en-US")),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Money {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let object = MoneyObject::deserialize(deserializer)?;
+        Ok(Money::new(
+            object.amount,
+            object.currency_code.as_deref().unwrap_or(DEFAULT_CURRENCY),
+        ))
+    }
+}
+
+/// Serde adapter for `Option<Money>` fields Uber sends as bare cents, e.g. `tip`, `manifest_total_value` and manifest item `price`.
+/// The currency isn't sent, integers are read back in [`DEFAULT_CURRENCY`]; responses that carry a `currency`
+/// re-tag their amounts with [`minor_units::in_currency`] once the whole object is read.
+pub mod minor_units {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::{Money, DEFAULT_CURRENCY};
+
+    pub fn serialize<S: Serializer>(money: &Option<Money>, serializer: S) -> Result<S::Ok, S::Error> {
+        match money {
+            Some(money) => serializer.serialize_i64(money.amount()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Money>, D::Error> {
+        Ok(Option::<i64>::deserialize(deserializer)?.map(|amount| Money::new(amount, DEFAULT_CURRENCY)))
+    }
+
+    /// An amount read by this adapter in the response's `currency`, [`DEFAULT_CURRENCY`] if it's missing
+    pub fn in_currency(money: Option<Money>, currency: Option<&str>) -> Option<Money> {
+        Money::from_minor_units(money.map(|money| money.amount()), currency)
+    }
+}
+
+/// Serde adapter for the Eats `Option<Money>` fields, e.g. order `charges`. Uber sometimes leaves the `amount` out of a money object,
+/// that object is read as `None` rather than failing the whole order or passing for a zero amount.
+pub mod optional_amount {
+    use serde::{Deserialize, Deserializer};
+
+    use super::{Money, DEFAULT_CURRENCY};
+
+    #[derive(Deserialize)]
+    struct PartialMoney {
+        #[serde(default)]
+        amount: Option<i64>,
+        #[serde(default)]
+        currency_code: Option<String>,
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Money>, D::Error> {
+        Ok(Option::<PartialMoney>::deserialize(deserializer)?.and_then(|money| {
+            let currency = money.currency_code.as_deref().unwrap_or(DEFAULT_CURRENCY);
+            money.amount.map(|amount| Money::new(amount, currency))
+        }))
+    }
+}
+
+/// ISO 4217 minor unit exponent, 2 for anything not listed
+pub fn currency_exponent(currency: &str) -> u32 {
+    match currency.to_ascii_uppercase().as_str() {
+        "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF" | "UGX" | "UYI" | "VND"
+        | "VUV" | "XAF" | "XOF" | "XPF" => 0,
+        "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+        _ => 2,
+    }
+}
+
+fn currency_symbol(currency: &str) -> Option<&'static str> {
+    match currency {
+        "USD" | "CAD" | "AUD" | "NZD" | "MXN" => Some("$"),
+        "EUR" => Some("€"),
+        "GBP" => Some("£"),
+        "JPY" => Some("¥"),
+        "BRL" => Some("R$"),
+        "INR" => Some("₹"),
+        "KRW" => Some("₩"),
+        "ZAR" => Some("R"),
+        _ => None,
+    }
+}
+
+struct LocaleStyle {
+    decimal_separator: char,
+    group_separator: char,
+    symbol_first: bool,
+    // only matters for a leading symbol, a trailing one is always spaced
+    symbol_spaced: bool,
+}
+
+impl LocaleStyle {
+    fn for_locale(locale: &str) -> Self {
+        let locale = locale.replace('_', "-").to_ascii_lowercase();
+        let language = locale.split('-').next().unwrap_or_default();
+
+        match (language, locale.as_str()) {
+            (_, "pt-br") | ("nl", _) => LocaleStyle { decimal_separator: ',', group_separator: '.', symbol_first: true, symbol_spaced: true },
+            ("de" | "es" | "it" | "pt" | "da" | "id" | "tr" | "el" | "ro" | "hr" | "sl" | "vi", _) => {
+                LocaleStyle { decimal_separator: ',', group_separator: '.', symbol_first: false, symbol_spaced: true }
+            }
+            ("fr" | "ru" | "pl" | "cs" | "sk" | "sv" | "nb" | "no" | "fi" | "uk" | "hu" | "bg" | "lt" | "lv" | "et", _) => {
+                LocaleStyle { decimal_separator: ',', group_separator: '\u{a0}', symbol_first: false, symbol_spaced: true }
+            }
+            _ => LocaleStyle { decimal_separator: '.', group_separator: ',', symbol_first: true, symbol_spaced: false },
+        }
+    }
+}
+
+fn group_digits(digits: &str, separator: char) -> String {
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+fn overflow(left: &Money, op: &str, right: &Money) -> UberError {
+    UberError::InvalidState(format!("{} {} {} overflows", left, op, right))
+}
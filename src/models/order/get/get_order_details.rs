@@ -1,5 +1,10 @@
 use serde::{Serialize, Deserialize};
 
+use crate::models::money::optional_amount;
+
+// `Money` lived here before it was shared with the Direct models
+pub use crate::models::money::Money;
+
 /// # Request Parameters
 ///
 /// up-to-date documentation can be found here -> https://developer.uber.com/docs/eats/references/api/v2/get-eats-order-orderid
@@ -163,53 +168,42 @@ pub struct Payment {
 #[derive(Serialize, Deserialize, Default, Debug)]
 #[serde(rename_all = "snake_case")]
 pub struct Charges {
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "optional_amount::deserialize")]
     pub total: Option<Money>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "optional_amount::deserialize")]
     pub sub_total: Option<Money>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "optional_amount::deserialize")]
     pub tax: Option<Money>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "optional_amount::deserialize")]
     pub total_fee: Option<Money>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "optional_amount::deserialize")]
     pub total_fee_tax: Option<Money>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "optional_amount::deserialize")]
     pub bag_fee: Option<Money>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "optional_amount::deserialize")]
     pub total_promo_applied: Option<Money>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "optional_amount::deserialize")]
     pub sub_total_promo_applied: Option<Money>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "optional_amount::deserialize")]
     pub tax_promo_applied: Option<Money>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "optional_amount::deserialize")]
     pub pick_and_pack_fee: Option<Money>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "optional_amount::deserialize")]
     pub delivery_fee: Option<Money>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "optional_amount::deserialize")]
     pub delivery_fee_tax: Option<Money>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "optional_amount::deserialize")]
     pub small_order_fee: Option<Money>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "optional_amount::deserialize")]
     pub small_order_fee_tax: Option<Money>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "optional_amount::deserialize")]
     pub tip: Option<Money>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "optional_amount::deserialize")]
     pub cash_amount_due: Option<Money>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "optional_amount::deserialize")]
     pub marketplace_fee_due_to_uber: Option<Money>,
 }
 
-#[derive(Serialize, Deserialize, Default, Debug)]
-#[serde(rename_all = "snake_case")]
-pub struct Money {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub amount: Option<u32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub currency_code: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub formatted_amount: Option<String>,
-}
-
 #[derive(Serialize, Deserialize, Default, Debug)]
 #[serde(rename_all = "snake_case")]
 pub struct Accounting {
@@ -248,7 +242,7 @@ pub struct RemittanceInfo {
 #[derive(Serialize, Deserialize, Default, Debug)]
 #[serde(rename_all = "snake_case")]
 pub struct PayeeDetail {
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "optional_amount::deserialize")]
     pub value: Option<Money>,
 }
 
@@ -282,11 +276,11 @@ pub struct TaxInfo {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "type")]
     pub tax_type: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "optional_amount::deserialize")]
     pub gross_amount: Option<Money>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "optional_amount::deserialize")]
     pub net_amount: Option<Money>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "optional_amount::deserialize")]
     pub total_tax: Option<Money>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub taxes: Option<Vec<Tax>>,
@@ -297,7 +291,7 @@ pub struct TaxInfo {
 pub struct Tax {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rate: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "optional_amount::deserialize")]
     pub tax_amount: Option<Money>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_inclusive: Option<bool>,
@@ -425,13 +419,13 @@ pub struct TaxI {
 #[derive(Serialize, Deserialize, Default, Debug)]
 #[serde(rename_all = "snake_case")]
 pub struct ItemPrice {
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "optional_amount::deserialize")]
     pub unit_price: Option<Money>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "optional_amount::deserialize")]
     pub total_price: Option<Money>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "optional_amount::deserialize")]
     pub base_unit_price: Option<Money>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "optional_amount::deserialize")]
     pub base_total_price: Option<Money>,
 }
 
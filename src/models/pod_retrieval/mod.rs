@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 mod pod_retrieval;
 
 pub use pod_retrieval::*;
\ No newline at end of file
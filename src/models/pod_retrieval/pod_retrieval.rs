@@ -1,19 +1,190 @@
+use std::fmt;
+use std::path::Path;
+
+use base64::Engine;
+use base64::engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig};
 use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::errors::UberError;
+
+// Uber pads the document, but tolerate copies that lost it
+const BASE64: GeneralPurpose = GeneralPurpose::new(
+    &base64::alphabet::STANDARD,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+const JPEG_SIGNATURE: [u8; 3] = [0xff, 0xd8, 0xff];
 
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "snake_case")]
 pub struct PODRetrievalResponse {
     pub document: Option<String>,
 }
-/// # Request Body Parameters - 
+
+impl PODRetrievalResponse {
+    /// Decode the base64 `document`, `UberError::NotFound` when Uber didn't send one
+    pub fn decode(&self) -> Result<ProofOfDelivery, UberError> {
+        match self.document.as_deref() {
+            Some(document) if !document.trim().is_empty() => ProofOfDelivery::from_base64(document),
+            _ => Err(UberError::NotFound("proof of delivery response has no document".to_string())),
+        }
+    }
+}
+
+/// # Request Body Parameters -
 ///
-/// |Name	|Type|	Description|
+/// |Name |Type| Description|
 /// | :--- | :--- | :--- |
-/// |waypoint|	string|	Waypoint can be “pickup” or “dropoff” or “return”.|
-/// |type|string|	Type can be “picture” or “signature” or “pincode”.|
+/// |waypoint| PodWaypoint| Waypoint can be “pickup” or “dropoff” or “return”.|
+/// |type|PodType| Type can be “picture” or “signature” or “pincode”.|
 ///
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PODRetrievalRequest {
-    pub waypoint: String,
-    pub type_of_waypoint: String,
-}
\ No newline at end of file
+    pub waypoint: PodWaypoint,
+    #[serde(rename = "type")]
+    pub pod_type: PodType,
+}
+
+impl PODRetrievalRequest {
+    pub fn new(waypoint: PodWaypoint, pod_type: PodType) -> Self {
+        PODRetrievalRequest { waypoint, pod_type }
+    }
+}
+
+/// Waypoint the proof of delivery was captured at
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum PodWaypoint {
+    Pickup,
+    Dropoff,
+    Return,
+}
+
+impl PodWaypoint {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pickup => "pickup",
+            Self::Dropoff => "dropoff",
+            Self::Return => "return",
+        }
+    }
+}
+
+impl fmt::Display for PodWaypoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Kind of proof, matching the verification requirement the delivery was created with
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum PodType {
+    Picture,
+    Signature,
+    Pincode,
+}
+
+impl PodType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Picture => "picture",
+            Self::Signature => "signature",
+            Self::Pincode => "pincode",
+        }
+    }
+}
+
+impl fmt::Display for PodType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Image format of a proof of delivery, sniffed from its first bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageFormat {
+    Png,
+    Jpeg,
+    Unknown,
+}
+
+impl ImageFormat {
+    pub fn detect(bytes: &[u8]) -> Self {
+        if bytes.starts_with(&PNG_SIGNATURE) {
+            Self::Png
+        } else if bytes.starts_with(&JPEG_SIGNATURE) {
+            Self::Jpeg
+        } else {
+            Self::Unknown
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Jpeg => "jpg",
+            Self::Unknown => "bin",
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Png => "image/png",
+            Self::Jpeg => "image/jpeg",
+            Self::Unknown => "application/octet-stream",
+        }
+    }
+}
+
+/// A decoded proof of delivery image, from [`PODRetrievalResponse::decode`] or [`crate::UberClient::proof_of_delivery`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofOfDelivery {
+    bytes: Vec<u8>,
+    format: ImageFormat,
+}
+
+impl ProofOfDelivery {
+    /// Decode a base64 document, with or without a `data:image/...;base64,` prefix and line breaks
+    pub fn from_base64(document: &str) -> Result<Self, UberError> {
+        let document = match document.split_once(";base64,") {
+            Some((prefix, data)) if prefix.starts_with("data:") => data,
+            _ => document,
+        };
+        let cleaned: String = document.chars().filter(|c| !c.is_ascii_whitespace()).collect();
+
+        Ok(Self::from_bytes(BASE64.decode(cleaned)?))
+    }
+
+    pub fn from_bytes(bytes: Vec<u8>) -> Self {
+        let format = ImageFormat::detect(&bytes);
+        ProofOfDelivery { bytes, format }
+    }
+
+    pub fn format(&self) -> ImageFormat {
+        self.format
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Write the image to `path`, creating or truncating the file. The path is used as is, see [`ImageFormat::extension`] to name it.
+    pub async fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), UberError> {
+        tokio::fs::write(path, &self.bytes).await?;
+        Ok(())
+    }
+
+    /// Write the image to `writer` and flush it
+    pub async fn write_to<W: AsyncWrite + Unpin>(&self, writer: &mut W) -> Result<(), UberError> {
+        writer.write_all(&self.bytes).await?;
+        writer.flush().await?;
+        Ok(())
+    }
+}
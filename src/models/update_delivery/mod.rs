@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 mod update_delivery;
 
 pub use update_delivery::*;
\ No newline at end of file
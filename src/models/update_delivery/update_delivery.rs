@@ -1,66 +1,27 @@
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
 
-use crate::models::general::{
-    CourierInfo,
-    LocalDateTime,
-    WaypointInfo,
-    ManifestInfo,
-    ManifestItem,
-    RelatedDelivery,
-    VerificationRequirement
-};
+use crate::models::delivery::Delivery;
+use crate::models::general::VerificationRequirement;
+use crate::models::money::{minor_units, Money};
+
+/// Update delivery answers with the shared [`Delivery`]
+pub type UpdateDeliveryResponse = Delivery;
 
-#[derive(Deserialize, Debug)]
-#[serde(rename_all = "snake_case")]
-pub struct UpdateDeliveryResponse {
-    pub complete: Option<bool>,
-    pub courier: Option<CourierInfo>,
-    pub courier_imminent: Option<bool>,
-    pub created: Option<LocalDateTime>,
-    pub currency: Option<String>,
-    pub deliverable_action: Option<String>,
-    pub dropoff: Option<WaypointInfo>,
-    pub dropoff_deadline: Option<LocalDateTime>,
-    pub dropoff_eta: Option<LocalDateTime>,
-    pub dropoff_identifier: Option<String>,
-    pub dropoff_ready: Option<LocalDateTime>,
-    pub fee: Option<u32>,
-    pub id: Option<String>,
-    pub kind: Option<String>,
-    pub live_mode: Option<bool>,
-    pub manifest: Option<ManifestInfo>,
-    pub manifest_items:	Option<Vec<ManifestItem>>,
-    pub pickup:	Option<WaypointInfo>,
-    pub pickup_deadline: Option<LocalDateTime>,
-    pub pickup_eta: Option<LocalDateTime>,
-    pub pickup_ready: Option<LocalDateTime>,
-    pub quote_id: Option<String>,
-    pub related_deliveries: Option<RelatedDelivery>,
-    pub status: Option<String>,
-    pub tip: Option<u32>,
-    pub tracking_url: Option<String>,
-    pub undeliverable_action: Option<String>,
-    pub undeliverable_reason: Option<String>,
-    pub updated: Option<LocalDateTime>,
-    pub uuid: Option<String>,
-    #[serde(rename = "return")]
-    pub return_waypoint: Option<WaypointInfo>,
-}
 /// # Request Body Parameters
 ///
-/// |Name|	Type	|Description|
+/// |Name| Type |Description|
 /// | :--- | :--- | :--- |
-/// |dropoff_notes|	string	|Additional instructions for the courier at the dropoff location. Max 280 characters.|
-/// |dropoff_seller_notes|	string|	Additional instructions provided by the merchant for the dropoff. Max 280 characters.|
-/// |dropoff_verification|	VerificationRequirement|	Verification steps (i.e. barcode scanning) that must be taken before the dropoff can be completed.|
-/// |manifest_reference	|string	|Reference that identifies the manifest. Use this to connect a delivery to corresponding information in your system.|
-/// |pickup_notes	|string|	Additional instructions for the courier at the pickup location. Max 280 characters.|
-/// |pickup_verification	|VerificationRequirement	|Verification steps (i.e. barcode scanning) that must be taken before the pickup can be completed.|
-/// |requires_dropoff_signature	|boolean|	Flag to indicate this delivery requires signature capture at dropoff.|
-/// |requires_id	|boolean	|Flag to indicate this delivery requires ID verification.|
-/// |tip_by_customer	|integer|	Amount in cents that will be paid to the courier as a tip.|
-/// |dropoff_latitude	|double|	Dropoff latitude coordinate.|
-/// |dropoff_longitude	|double|	Dropoff longitude coordinate.|
+/// |dropoff_notes| string |Additional instructions for the courier at the dropoff location. Max 280 characters.|
+/// |dropoff_seller_notes| string| Additional instructions provided by the merchant for the dropoff. Max 280 characters.|
+/// |dropoff_verification| VerificationRequirement| Verification steps (i.e. barcode scanning) that must be taken before the dropoff can be completed.|
+/// |manifest_reference |string |Reference that identifies the manifest. Use this to connect a delivery to corresponding information in your system.|
+/// |pickup_notes |string| Additional instructions for the courier at the pickup location. Max 280 characters.|
+/// |pickup_verification |VerificationRequirement |Verification steps (i.e. barcode scanning) that must be taken before the pickup can be completed.|
+/// |requires_dropoff_signature |boolean| Flag to indicate this delivery requires signature capture at dropoff.|
+/// |requires_id |boolean |Flag to indicate this delivery requires ID verification.|
+/// |tip_by_customer |Money| Amount that will be paid to the courier as a tip, sent in cents.|
+/// |dropoff_latitude |double| Dropoff latitude coordinate.|
+/// |dropoff_longitude |double| Dropoff longitude coordinate.|
 ///
 #[derive(Serialize, Default)]
 pub struct UpdateDeliveryRequest {
@@ -80,8 +41,8 @@ pub struct UpdateDeliveryRequest {
     pub requires_dropoff_signature: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub requires_id: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub tip_by_customer: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "minor_units::serialize")]
+    pub tip_by_customer: Option<Money>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dropoff_latitude: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
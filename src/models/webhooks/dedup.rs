@@ -0,0 +1,212 @@
+use std::future::Future;
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+
+use lru::LruCache;
+
+use crate::errors::UberError;
+use crate::models::general::Timestamp;
+use crate::models::webhooks::{CourierUpdate, DeliveryStatus, WebhookEvent, WebhookHandler};
+
+/// Event ids, and deliveries, remembered by a [`MemoryEventStore`] before the least recently used are forgotten
+pub const DEFAULT_DEDUP_CAPACITY: usize = 10_000;
+
+/// Where [`Deduplicate`] remembers the webhooks it already handled. Implement it over a shared store (Redis, a database table)
+/// when several processes receive webhooks.
+pub trait SeenEventStore: Send + Sync {
+    /// Claim `event_id` for handling, `false` when it was already claimed
+    fn claim(&self, event_id: &str) -> impl Future<Output = Result<bool, UberError>> + Send;
+
+    /// Give back a claim whose handling failed, so Uber's retry of the event is handled
+    fn release(&self, event_id: &str) -> impl Future<Output = Result<(), UberError>> + Send;
+
+    /// `created` of the latest status event claimed for `delivery_id`, being handled or applied
+    fn last_applied(&self, delivery_id: &str) -> impl Future<Output = Result<Option<Timestamp>, UberError>> + Send;
+
+    /// Claim `delivery_id` for a status event created at `created`, `false` when a later one was already claimed.
+    /// Checking and recording must be one atomic step, or two events handled concurrently could both pass.
+    fn claim_applied(&self, delivery_id: &str, created: Timestamp) -> impl Future<Output = Result<bool, UberError>> + Send;
+
+    /// Give back a `claim_applied` whose handling failed, unless a later event claimed `delivery_id` since
+    fn release_applied(&self, delivery_id: &str, created: Timestamp) -> impl Future<Output = Result<(), UberError>> + Send;
+}
+
+/// In-memory [`SeenEventStore`], forgetting the least recently used event ids and deliveries past its capacity
+#[derive(Debug)]
+pub struct MemoryEventStore {
+    events: Mutex<LruCache<String, ()>>,
+    deliveries: Mutex<LruCache<String, AppliedClaim>>,
+}
+
+// the latest status claimed for a delivery, and the one it replaced to go back to if handling it fails
+#[derive(Debug, Clone, Copy)]
+struct AppliedClaim {
+    created: Timestamp,
+    previous: Option<Timestamp>,
+}
+
+impl Default for MemoryEventStore {
+    fn default() -> Self {
+        MemoryEventStore::new(DEFAULT_DEDUP_CAPACITY)
+    }
+}
+
+impl MemoryEventStore {
+    /// `capacity` applies to event ids and deliveries separately, and is at least 1
+    pub fn new(capacity: usize) -> Self {
+        let capacity = NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN);
+        MemoryEventStore {
+            events: Mutex::new(LruCache::new(capacity)),
+            deliveries: Mutex::new(LruCache::new(capacity)),
+        }
+    }
+}
+
+impl SeenEventStore for MemoryEventStore {
+    async fn claim(&self, event_id: &str) -> Result<bool, UberError> {
+        let mut events = self.events.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        Ok(events.put(event_id.to_string(), ()).is_none())
+    }
+
+    async fn release(&self, event_id: &str) -> Result<(), UberError> {
+        self.events.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).pop(event_id);
+        Ok(())
+    }
+
+    async fn last_applied(&self, delivery_id: &str) -> Result<Option<Timestamp>, UberError> {
+        let mut deliveries = self.deliveries.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        Ok(deliveries.get(delivery_id).map(|claim| claim.created))
+    }
+
+    async fn claim_applied(&self, delivery_id: &str, created: Timestamp) -> Result<bool, UberError> {
+        let mut deliveries = self.deliveries.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let previous = deliveries.get(delivery_id).map(|claim| claim.created);
+        if previous.is_some_and(|previous| created < previous) {
+            return Ok(false);
+        }
+
+        deliveries.put(delivery_id.to_string(), AppliedClaim { created, previous });
+        Ok(true)
+    }
+
+    async fn release_applied(&self, delivery_id: &str, created: Timestamp) -> Result<(), UberError> {
+        let mut deliveries = self.deliveries.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let previous = match deliveries.peek(delivery_id) {
+            Some(claim) if claim.created == created => claim.previous,
+            _ => return Ok(()),
+        };
+
+        match previous {
+            Some(previous) => {
+                deliveries.put(delivery_id.to_string(), AppliedClaim { created: previous, previous: None });
+            }
+            None => {
+                deliveries.pop(delivery_id);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A [`WebhookHandler`] that drops webhooks Uber already delivered, and status events older than the last one applied
+///
+/// - An event whose `id` was already handled is acknowledged without calling the handler again.
+/// - A status or return event `created` before the last status applied to its `delivery_id` is dropped, so a late `pickup`
+///   never overwrites `delivered`. Events without `created` or `delivery_id` are always applied.
+/// - The delivery is claimed before the handler is called, so a `pickup` arriving while `delivered` is being handled is dropped too.
+/// - When the handler fails the event id and the delivery claim are released, so Uber's retry gets handled.
+///
+/// Only [`WebhookHandler::handle`] deduplicates, calling the `on_*` methods directly goes straight to the wrapped handler.
+///
+/// Example:
+/// ```no_run
+/// # fn run<H: uber_api::models::webhooks::WebhookHandler + 'static>(handler: H) {
+/// use uber_api::models::webhooks::{Deduplicate, MemoryEventStore};
+///
+/// let handler = Deduplicate::new(handler).with_store(MemoryEventStore::new(50_000));
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Deduplicate<H, S = MemoryEventStore> {
+    handler: H,
+    store: S,
+}
+
+impl<H: WebhookHandler> Deduplicate<H> {
+    /// Deduplicate with a [`MemoryEventStore`] of [`DEFAULT_DEDUP_CAPACITY`]
+    pub fn new(handler: H) -> Self {
+        Deduplicate {
+            handler,
+            store: MemoryEventStore::default(),
+        }
+    }
+}
+
+impl<H: WebhookHandler, S: SeenEventStore> Deduplicate<H, S> {
+    pub fn with_store<T: SeenEventStore>(self, store: T) -> Deduplicate<H, T> {
+        Deduplicate {
+            handler: self.handler,
+            store,
+        }
+    }
+
+    pub fn handler(&self) -> &H {
+        &self.handler
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    async fn handle_new(&self, event: WebhookEvent) -> Result<(), UberError> {
+        let applied = event.delivery_id().map(str::to_string).zip(event.created());
+        if let Some((delivery_id, created)) = &applied {
+            if !self.store.claim_applied(delivery_id, *created).await? {
+                log::info!("Dropping stale {} webhook for delivery {}", event.kind(), delivery_id);
+                return Ok(());
+            }
+        }
+
+        let result = self.handler.handle(event).await;
+        if let (Err(_), Some((delivery_id, created))) = (&result, &applied) {
+            self.store.release_applied(delivery_id, *created).await?;
+        }
+        result
+    }
+}
+
+impl<H: WebhookHandler, S: SeenEventStore> WebhookHandler for Deduplicate<H, S> {
+    fn on_delivery_status(&self, event: DeliveryStatus) -> impl Future<Output = Result<(), UberError>> + Send {
+        self.handler.on_delivery_status(event)
+    }
+
+    fn on_delivery_return(&self, event: DeliveryStatus) -> impl Future<Output = Result<(), UberError>> + Send {
+        self.handler.on_delivery_return(event)
+    }
+
+    fn on_courier_update(&self, event: CourierUpdate) -> impl Future<Output = Result<(), UberError>> + Send {
+        self.handler.on_courier_update(event)
+    }
+
+    fn on_unknown(&self, kind: Option<String>, payload: serde_json::Value) -> impl Future<Output = Result<(), UberError>> + Send {
+        self.handler.on_unknown(kind, payload)
+    }
+
+    async fn handle(&self, event: WebhookEvent) -> Result<(), UberError> {
+        let event_id = match event.event_id() {
+            Some(event_id) => event_id.to_string(),
+            None => return self.handle_new(event).await,
+        };
+
+        if !self.store.claim(&event_id).await? {
+            log::info!("Dropping repeated webhook {}", event_id);
+            return Ok(());
+        }
+
+        let result = self.handle_new(event).await;
+        if result.is_err() {
+            self.store.release(&event_id).await?;
+        }
+        result
+    }
+}
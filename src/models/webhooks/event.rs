@@ -0,0 +1,157 @@
+use std::future::Future;
+
+use serde::{Deserialize, Deserializer};
+use serde::de::Error as _;
+
+use crate::errors::UberError;
+use crate::models::general::Timestamp;
+use crate::models::webhooks::{CourierUpdate, DeliveryStatus};
+
+pub const DELIVERY_STATUS_KIND: &str = "event.delivery_status";
+pub const DELIVERY_RETURN_KIND: &str = "event.delivery_return";
+pub const COURIER_UPDATE_KIND: &str = "event.courier_update";
+
+/// # Webhook Event
+///
+/// A webhook payload, picked by its `kind`:
+///
+/// | kind | Variant |
+/// | :--- | :--- |
+/// | event.delivery_status | `DeliveryStatus` |
+/// | event.delivery_return | `DeliveryReturn` |
+/// | event.courier_update | `CourierUpdate` |
+/// | anything else, or no kind | `Unknown`, with the payload kept as JSON |
+///
+/// Example:
+/// ```
+/// use uber_api::models::webhooks::{parse_webhook, WebhookEvent};
+///
+/// let event = parse_webhook(br#"{"kind":"event.courier_update","location":{"lat":40.7,"lng":-73.9},"delivery_id":"del_1"}"#)?;
+///
+/// assert!(matches!(event, WebhookEvent::CourierUpdate(_)));
+/// assert_eq!(event.delivery_id(), Some("del_1"));
+/// # Ok::<(), uber_api::UberError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub enum WebhookEvent {
+    DeliveryStatus(DeliveryStatus),
+    DeliveryReturn(DeliveryStatus),
+    CourierUpdate(CourierUpdate),
+    Unknown {
+        kind: Option<String>,
+        payload: serde_json::Value,
+    },
+}
+
+impl<'de> Deserialize<'de> for WebhookEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let payload = serde_json::Value::deserialize(deserializer)?;
+        let kind = payload.get("kind").and_then(serde_json::Value::as_str).map(str::to_string);
+
+        let event = match kind.as_deref() {
+            Some(DELIVERY_STATUS_KIND) => Self::DeliveryStatus(DeliveryStatus::deserialize(payload).map_err(D::Error::custom)?),
+            Some(DELIVERY_RETURN_KIND) => Self::DeliveryReturn(DeliveryStatus::deserialize(payload).map_err(D::Error::custom)?),
+            Some(COURIER_UPDATE_KIND) => Self::CourierUpdate(CourierUpdate::deserialize(payload).map_err(D::Error::custom)?),
+            _ => Self::Unknown { kind, payload },
+        };
+
+        Ok(event)
+    }
+}
+
+impl WebhookEvent {
+    /// The payload's `kind`, empty for an `Unknown` event without one
+    pub fn kind(&self) -> &str {
+        match self {
+            Self::DeliveryStatus(event) | Self::DeliveryReturn(event) => &event.kind,
+            Self::CourierUpdate(event) => &event.kind,
+            Self::Unknown { kind, .. } => kind.as_deref().unwrap_or_default(),
+        }
+    }
+
+    pub fn delivery_id(&self) -> Option<&str> {
+        match self {
+            Self::DeliveryStatus(event) | Self::DeliveryReturn(event) => event.delivery_id.as_deref(),
+            Self::CourierUpdate(event) => event.delivery_id.as_deref(),
+            Self::Unknown { payload, .. } => payload.get("delivery_id").and_then(serde_json::Value::as_str),
+        }
+    }
+
+    /// The unique id of this event instance, Uber sends the same id again when it retries a webhook. Courier updates have none.
+    pub fn event_id(&self) -> Option<&str> {
+        match self {
+            Self::DeliveryStatus(event) | Self::DeliveryReturn(event) => event.id.as_deref(),
+            Self::CourierUpdate(_) => None,
+            Self::Unknown { payload, .. } => payload.get("id").and_then(serde_json::Value::as_str),
+        }
+    }
+
+    /// When a status event was generated, `None` for other events
+    pub fn created(&self) -> Option<Timestamp> {
+        match self {
+            Self::DeliveryStatus(event) | Self::DeliveryReturn(event) => event.created,
+            _ => None,
+        }
+    }
+}
+
+/// Parse a raw webhook body into a [`WebhookEvent`]. Check the signature with [`crate::models::webhooks::verify_webhook`] first.
+pub fn parse_webhook(raw_body: &[u8]) -> Result<WebhookEvent, UberError> {
+    Ok(serde_json::from_slice(raw_body)?)
+}
+
+/// Receives parsed webhook events, one method per kind. Every method defaults to ignoring the event.
+///
+/// An error tells Uber the webhook wasn't handled, so it will be sent again.
+///
+/// Example:
+/// ```
+/// use uber_api::UberError;
+/// use uber_api::models::webhooks::{parse_webhook, DeliveryStatus, WebhookHandler};
+///
+/// struct StatusLogger;
+///
+/// impl WebhookHandler for StatusLogger {
+///     async fn on_delivery_status(&self, event: DeliveryStatus) -> Result<(), UberError> {
+///         log::info!("Delivery {:?} is now {}", event.delivery_id, event.status);
+///         Ok(())
+///     }
+/// }
+///
+/// # async fn run(raw_body: &[u8]) -> Result<(), UberError> {
+/// StatusLogger.handle(parse_webhook(raw_body)?).await
+/// # }
+/// ```
+pub trait WebhookHandler: Send + Sync {
+    fn on_delivery_status(&self, event: DeliveryStatus) -> impl Future<Output = Result<(), UberError>> + Send {
+        let _ = event;
+        async { Ok(()) }
+    }
+
+    /// Defaults to [`WebhookHandler::on_delivery_status`], a return is a status change of the original delivery
+    fn on_delivery_return(&self, event: DeliveryStatus) -> impl Future<Output = Result<(), UberError>> + Send {
+        self.on_delivery_status(event)
+    }
+
+    fn on_courier_update(&self, event: CourierUpdate) -> impl Future<Output = Result<(), UberError>> + Send {
+        let _ = event;
+        async { Ok(()) }
+    }
+
+    fn on_unknown(&self, kind: Option<String>, payload: serde_json::Value) -> impl Future<Output = Result<(), UberError>> + Send {
+        let _ = (kind, payload);
+        async { Ok(()) }
+    }
+
+    /// Dispatch `event` to the method for its kind
+    fn handle(&self, event: WebhookEvent) -> impl Future<Output = Result<(), UberError>> + Send {
+        async move {
+            match event {
+                WebhookEvent::DeliveryStatus(event) => self.on_delivery_status(event).await,
+                WebhookEvent::DeliveryReturn(event) => self.on_delivery_return(event).await,
+                WebhookEvent::CourierUpdate(event) => self.on_courier_update(event).await,
+                WebhookEvent::Unknown { kind, payload } => self.on_unknown(kind, payload).await,
+            }
+        }
+    }
+}
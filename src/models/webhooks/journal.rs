@@ -0,0 +1,256 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use tokio::fs::{File, OpenOptions};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::sync::Mutex;
+
+use crate::errors::UberError;
+use crate::models::general::Timestamp;
+use crate::models::webhooks::{parse_webhook, verify_webhook, WebhookEvent, WebhookHandler, POSTMATES_SIGNATURE_HEADER, UBER_SIGNATURE_HEADER};
+
+/// Request headers kept in a [`JournalEntry`], enough to verify and parse a replayed body. Others (cookies, authorization
+/// added by a proxy) are never written to the journal.
+pub const JOURNALED_HEADERS: [&str; 3] = [UBER_SIGNATURE_HEADER, POSTMATES_SIGNATURE_HEADER, "content-type"];
+
+/// # Journal Entry
+///
+/// One webhook as it was received, a line of a [`WebhookJournal`].
+///
+/// | Name | Type | Description |
+/// | :--- | :--- | :--- |
+/// | received | Timestamp | When the webhook was received. |
+/// | headers | map | The [`JOURNALED_HEADERS`] received, names lowercased. The signature header is kept, so a replayed body still verifies. |
+/// | body | bytes | The raw request body, base64 encoded in the journal so it replays byte for byte and its signature still matches. |
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct JournalEntry {
+    pub received: Timestamp,
+    pub headers: BTreeMap<String, String>,
+    #[serde(with = "base64_body")]
+    pub body: Vec<u8>,
+}
+
+impl JournalEntry {
+    /// An entry for a webhook received now. Only [`JOURNALED_HEADERS`] are kept, header values that aren't text are left out.
+    pub fn new(headers: &HeaderMap, raw_body: &[u8]) -> Self {
+        JournalEntry {
+            received: Timestamp::now(),
+            headers: JOURNALED_HEADERS.iter()
+                .filter_map(|name| Some((name.to_string(), headers.get(*name)?.to_str().ok()?.to_string())))
+                .collect(),
+            body: raw_body.to_vec(),
+        }
+    }
+
+    pub fn event(&self) -> Result<WebhookEvent, UberError> {
+        parse_webhook(&self.body)
+    }
+
+    /// Check the journaled signature against the journaled body, see [`verify_webhook`]
+    pub fn verify(&self, signing_key: &str) -> Result<(), UberError> {
+        verify_webhook(signing_key, &self.header_map(), &self.body)
+    }
+
+    pub fn header_map(&self) -> HeaderMap {
+        self.headers.iter()
+            .filter_map(|(name, value)| Some((HeaderName::try_from(name.as_str()).ok()?, HeaderValue::from_str(value).ok()?)))
+            .collect()
+    }
+}
+
+mod base64_body {
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine;
+    use serde::de::Error as _;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(body: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&STANDARD.encode(body))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        STANDARD.decode(String::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+}
+
+/// Append-only JSON Lines journal of received webhooks, one [`JournalEntry`] per line
+///
+/// Attach it to a `WebhookServer` with `with_journal`, or call [`WebhookJournal::record`] from your own endpoint,
+/// then re-feed what was journaled with [`replay_journal`].
+///
+/// Webhook bodies carry customer names, phone numbers and addresses, and the journal keeps them until it's deleted:
+/// store it with the same care as your delivery data, and rotate or purge it once old webhooks no longer need replaying.
+///
+/// Example:
+/// ```no_run
+/// # async fn run(headers: reqwest::header::HeaderMap, raw_body: Vec<u8>) -> Result<(), uber_api::UberError> {
+/// use uber_api::models::webhooks::WebhookJournal;
+///
+/// let journal = WebhookJournal::open("webhooks.jsonl").await?;
+/// journal.record(&headers, &raw_body).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct WebhookJournal {
+    path: PathBuf,
+    file: Mutex<File>,
+}
+
+impl WebhookJournal {
+    /// Open the journal at `path` for appending, creating it if needed
+    pub async fn open<P: AsRef<Path>>(path: P) -> Result<Self, UberError> {
+        let path = path.as_ref().to_path_buf();
+        let file = OpenOptions::new().create(true).append(true).open(&path).await?;
+
+        Ok(WebhookJournal {
+            path,
+            file: Mutex::new(file),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Journal a webhook received now
+    pub async fn record(&self, headers: &HeaderMap, raw_body: &[u8]) -> Result<(), UberError> {
+        self.append(&JournalEntry::new(headers, raw_body)).await
+    }
+
+    pub async fn append(&self, entry: &JournalEntry) -> Result<(), UberError> {
+        let mut line = serde_json::to_string(entry)?;
+        line.push('\n');
+
+        let mut file = self.file.lock().await;
+        file.write_all(line.as_bytes()).await?;
+        file.flush().await?;
+        Ok(())
+    }
+}
+
+/// Which journaled webhooks to replay, everything by default
+///
+/// | Setting     | Description |
+/// |-------------|-------------|
+/// | from        | Received at or after this time. |
+/// | until       | Received before this time. |
+/// | delivery_id | Events for this delivery. |
+/// | kind        | Events of this kind, e.g. `event.delivery_status`. |
+#[derive(Debug, Clone, Default)]
+pub struct ReplayFilter {
+    from: Option<Timestamp>,
+    until: Option<Timestamp>,
+    delivery_id: Option<String>,
+    kind: Option<String>,
+}
+
+impl ReplayFilter {
+    pub fn new() -> Self {
+        ReplayFilter::default()
+    }
+
+    pub fn with_from(mut self, from: Timestamp) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn with_until(mut self, until: Timestamp) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    pub fn with_delivery_id<T: Into<String>>(mut self, delivery_id: T) -> Self {
+        self.delivery_id = Some(delivery_id.into());
+        self
+    }
+
+    pub fn with_kind<T: Into<String>>(mut self, kind: T) -> Self {
+        self.kind = Some(kind.into());
+        self
+    }
+
+    pub fn matches(&self, entry: &JournalEntry, event: &WebhookEvent) -> bool {
+        self.from.is_none_or(|from| entry.received >= from)
+            && self.until.is_none_or(|until| entry.received < until)
+            && self.delivery_id.as_deref().is_none_or(|delivery_id| event.delivery_id() == Some(delivery_id))
+            && self.kind.as_deref().is_none_or(|kind| event.kind() == kind)
+    }
+}
+
+/// Outcome of [`replay_journal`]
+#[derive(Debug, Default)]
+pub struct JournalReplay {
+    /// Events the handler accepted
+    pub replayed: usize,
+    /// Entries left out by the filter
+    pub skipped: usize,
+    pub failures: Vec<ReplayFailure>,
+}
+
+/// A journal line that couldn't be read or parsed, or whose event the handler failed
+#[derive(Debug)]
+pub struct ReplayFailure {
+    /// 1-based line number in the journal
+    pub line: usize,
+    pub error: UberError,
+}
+
+/// Journaled webhooks matching `filter`, with their line numbers. Lines that can't be read (e.g. one cut short by a crash)
+/// or whose body isn't a webhook are logged and left out.
+pub async fn read_journal<P: AsRef<Path>>(path: P, filter: &ReplayFilter) -> Result<Vec<(usize, JournalEntry)>, UberError> {
+    let mut matching = Vec::new();
+    for_each_line(path.as_ref(), |line, parsed| match parsed {
+        Ok((entry, event)) if filter.matches(&entry, &event) => matching.push((line, entry)),
+        Ok(_) => {}
+        Err(err) => log::warn!("Skipping journal line {} => {}", line, err),
+    })
+    .await?;
+
+    Ok(matching)
+}
+
+/// Re-feed the journaled webhooks matching `filter` to `handler`, in the order they were received. Replaying goes on past failures,
+/// which are collected in the report. A handler wrapped in `Deduplicate` drops the events it already handled.
+pub async fn replay_journal<P: AsRef<Path>, H: WebhookHandler>(path: P, filter: &ReplayFilter, handler: &H) -> Result<JournalReplay, UberError> {
+    let mut events = Vec::new();
+    let mut report = JournalReplay::default();
+    for_each_line(path.as_ref(), |line, parsed| match parsed {
+        Ok((entry, event)) if filter.matches(&entry, &event) => events.push((line, event)),
+        Ok(_) => report.skipped += 1,
+        Err(error) => report.failures.push(ReplayFailure { line, error }),
+    })
+    .await?;
+
+    for (line, event) in events {
+        match handler.handle(event).await {
+            Ok(()) => report.replayed += 1,
+            Err(error) => report.failures.push(ReplayFailure { line, error }),
+        }
+    }
+
+    Ok(report)
+}
+
+async fn for_each_line<F>(path: &Path, mut on_line: F) -> Result<(), UberError>
+where
+    F: FnMut(usize, Result<(JournalEntry, WebhookEvent), UberError>),
+{
+    let mut lines = BufReader::new(File::open(path).await?).lines();
+    let mut line = 0;
+
+    while let Some(text) = lines.next_line().await? {
+        line += 1;
+        if text.trim().is_empty() {
+            continue;
+        }
+
+        let parsed = serde_json::from_str::<JournalEntry>(&text)
+            .map_err(UberError::from)
+            .and_then(|entry| entry.event().map(|event| (entry, event)));
+        on_line(line, parsed);
+    }
+
+    Ok(())
+}
@@ -1,3 +1,12 @@
+mod dedup;
+mod event;
+mod journal;
+mod signature;
+#[allow(clippy::module_inception)]
 mod webhooks;
 
+pub use dedup::*;
+pub use event::*;
+pub use journal::*;
+pub use signature::*;
 pub use webhooks::*;
\ No newline at end of file
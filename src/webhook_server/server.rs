@@ -0,0 +1,252 @@
+use std::convert::Infallible;
+use std::future::Future;
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4, TcpListener};
+use std::sync::Arc;
+
+use hyper::body::HttpBody;
+use hyper::header::{HeaderValue, CONTENT_TYPE};
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+
+use crate::errors::UberError;
+use crate::models::webhooks::{parse_webhook, verify_webhook, DeliveryStatusResponse, WebhookHandler, WebhookJournal};
+
+/// Address the webhook server listens on
+pub const DEFAULT_WEBHOOK_ADDRESS: SocketAddr = SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 8080));
+/// Path Uber is configured to POST webhooks to
+pub const DEFAULT_WEBHOOK_PATH: &str = "/webhooks/uber";
+/// Largest webhook body accepted, in bytes
+pub const DEFAULT_WEBHOOK_BODY_LIMIT: usize = 1024 * 1024;
+
+/// Small HTTP server receiving Uber webhooks, behind the `webhook-server` feature
+///
+/// Every POST to the path has its signature checked with [`verify_webhook`], is parsed with [`parse_webhook`]
+/// and is handed to the [`WebhookHandler`]. With a [`WebhookJournal`], every webhook with a valid signature is journaled before it's parsed.
+/// Uber gets a `DeliveryStatusResponse` back:
+///
+/// | Status | When |
+/// |--------|------|
+/// | 200    | The handler accepted the event. |
+/// | 400    | The body isn't a webhook payload. |
+/// | 401    | The signature is missing or doesn't match. |
+/// | 404 / 405 | Another path, or a method other than POST. |
+/// | 413    | The body is larger than the body limit. |
+/// | 500    | The handler returned an error, or the webhook couldn't be journaled, so Uber sends it again. |
+///
+/// | Setting    | Default          | Description |
+/// |------------|------------------|-------------|
+/// | address    | `0.0.0.0:8080`   | Address to listen on, port 0 picks a free port. |
+/// | path       | `/webhooks/uber` | Path webhooks are accepted on. |
+/// | body_limit | 1 MiB            | Larger bodies are rejected before being read. |
+/// | journal    | none             | Journal received webhooks, for replaying them later. |
+///
+/// Example:
+/// ```no_run
+/// # async fn run() -> Result<(), uber_api::UberError> {
+/// use uber_api::{UberError, WebhookServer};
+/// use uber_api::models::webhooks::{DeliveryStatus, WebhookHandler};
+///
+/// struct Deliveries;
+///
+/// impl WebhookHandler for Deliveries {
+///     async fn on_delivery_status(&self, event: DeliveryStatus) -> Result<(), UberError> {
+///         log::info!("Delivery {:?} is now {}", event.delivery_id, event.status);
+///         Ok(())
+///     }
+/// }
+///
+/// let server = WebhookServer::new("signing_key", Deliveries)
+///     .with_address("127.0.0.1:3000".parse().unwrap())
+///     .bind()?;
+/// log::info!("Receiving webhooks on {}", server.local_addr());
+///
+/// let shutdown = async { tokio::time::sleep(std::time::Duration::from_secs(60)).await };
+/// server.serve_with_shutdown(shutdown).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct WebhookServer<H> {
+    signing_key: String,
+    handler: H,
+    address: SocketAddr,
+    path: String,
+    body_limit: usize,
+    journal: Option<WebhookJournal>,
+}
+
+/// A [`WebhookServer`] bound to its address, ready to serve
+pub struct BoundWebhookServer<H> {
+    listener: TcpListener,
+    local_addr: SocketAddr,
+    receiver: Arc<Receiver<H>>,
+}
+
+impl<H: WebhookHandler + 'static> WebhookServer<H> {
+    /// `signing_key` is the webhook signing key from the Direct dashboard
+    pub fn new<T: Into<String>>(signing_key: T, handler: H) -> Self {
+        WebhookServer {
+            signing_key: signing_key.into(),
+            handler,
+            address: DEFAULT_WEBHOOK_ADDRESS,
+            path: DEFAULT_WEBHOOK_PATH.to_string(),
+            body_limit: DEFAULT_WEBHOOK_BODY_LIMIT,
+            journal: None,
+        }
+    }
+
+    pub fn with_address(mut self, address: SocketAddr) -> Self {
+        self.address = address;
+        self
+    }
+
+    pub fn with_path<T: Into<String>>(mut self, path: T) -> Self {
+        self.path = path.into();
+        self
+    }
+
+    pub fn with_body_limit(mut self, body_limit: usize) -> Self {
+        self.body_limit = body_limit;
+        self
+    }
+
+    pub fn with_journal(mut self, journal: WebhookJournal) -> Self {
+        self.journal = Some(journal);
+        self
+    }
+
+    /// Bind the address, failing with `UberError::IoError` when it's unavailable
+    pub fn bind(self) -> Result<BoundWebhookServer<H>, UberError> {
+        let listener = TcpListener::bind(self.address)?;
+        listener.set_nonblocking(true)?;
+        let local_addr = listener.local_addr()?;
+
+        Ok(BoundWebhookServer {
+            listener,
+            local_addr,
+            receiver: Arc::new(Receiver {
+                signing_key: self.signing_key,
+                handler: self.handler,
+                path: self.path,
+                body_limit: self.body_limit,
+                journal: self.journal,
+            }),
+        })
+    }
+
+    /// [`WebhookServer::bind`] then [`BoundWebhookServer::serve_with_shutdown`]
+    pub async fn serve_with_shutdown<F: Future<Output = ()>>(self, shutdown: F) -> Result<(), UberError> {
+        self.bind()?.serve_with_shutdown(shutdown).await
+    }
+}
+
+impl<H: WebhookHandler + 'static> BoundWebhookServer<H> {
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Serve until the process exits
+    pub async fn serve(self) -> Result<(), UberError> {
+        self.serve_with_shutdown(futures::future::pending()).await
+    }
+
+    /// Serve until `shutdown` completes, then stop accepting connections and let webhooks in progress finish
+    pub async fn serve_with_shutdown<F: Future<Output = ()>>(self, shutdown: F) -> Result<(), UberError> {
+        let receiver = self.receiver;
+        let make_service = make_service_fn(move |_| {
+            let receiver = receiver.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    let receiver = receiver.clone();
+                    async move { Ok::<_, Infallible>(receiver.respond(request).await) }
+                }))
+            }
+        });
+
+        log::info!("Receiving Uber webhooks on {}", self.local_addr);
+
+        Server::from_tcp(self.listener)
+            .map_err(anyhow::Error::from)?
+            .serve(make_service)
+            .with_graceful_shutdown(shutdown)
+            .await
+            .map_err(anyhow::Error::from)?;
+
+        Ok(())
+    }
+}
+
+// status and message answered for a webhook that wasn't handled
+type Rejection = (StatusCode, String);
+
+struct Receiver<H> {
+    signing_key: String,
+    handler: H,
+    path: String,
+    body_limit: usize,
+    journal: Option<WebhookJournal>,
+}
+
+impl<H: WebhookHandler> Receiver<H> {
+    async fn respond(&self, request: Request<Body>) -> Response<Body> {
+        let (status, reply) = match self.receive(request).await {
+            Ok(()) => (StatusCode::OK, DeliveryStatusResponse { success: true, message: None }),
+            Err((status, message)) => {
+                log::warn!("Webhook rejected with {} => {}", status, message);
+                (status, DeliveryStatusResponse { success: false, message: Some(message) })
+            }
+        };
+
+        let mut response = Response::new(Body::from(serde_json::to_vec(&reply).unwrap_or_default()));
+        *response.status_mut() = status;
+        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        response
+    }
+
+    async fn receive(&self, request: Request<Body>) -> Result<(), Rejection> {
+        if request.uri().path() != self.path {
+            return Err((StatusCode::NOT_FOUND, format!("webhooks are received on {}", self.path)));
+        }
+        if request.method() != Method::POST {
+            return Err((StatusCode::METHOD_NOT_ALLOWED, "webhooks must be POSTed".to_string()));
+        }
+
+        let (parts, body) = request.into_parts();
+        let raw_body = read_body(body, self.body_limit).await?;
+
+        verify_webhook(&self.signing_key, &parts.headers, &raw_body)
+            .map_err(|err| (StatusCode::UNAUTHORIZED, err.to_string()))?;
+        if let Some(journal) = &self.journal {
+            journal.record(&parts.headers, &raw_body)
+                .await
+                .map_err(|err| (StatusCode::INTERNAL_SERVER_ERROR, format!("couldn't journal webhook: {}", err)))?;
+        }
+        let event = parse_webhook(&raw_body)
+            .map_err(|err| (StatusCode::BAD_REQUEST, err.to_string()))?;
+
+        log::info!("Webhook {} for delivery {:?}", event.kind(), event.delivery_id());
+
+        self.handler.handle(event)
+            .await
+            .map_err(|err| (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()))
+    }
+}
+
+async fn read_body(mut body: Body, limit: usize) -> Result<Vec<u8>, Rejection> {
+    let too_large = || (StatusCode::PAYLOAD_TOO_LARGE, format!("webhook body is larger than {} bytes", limit));
+
+    // a Content-Length over the limit is rejected without reading the body
+    if body.size_hint().lower() > limit as u64 {
+        return Err(too_large());
+    }
+
+    let mut raw_body = Vec::new();
+    while let Some(chunk) = body.data().await {
+        let chunk = chunk.map_err(|err| (StatusCode::BAD_REQUEST, format!("couldn't read webhook body: {}", err)))?;
+        if raw_body.len() + chunk.len() > limit {
+            return Err(too_large());
+        }
+        raw_body.extend_from_slice(&chunk);
+    }
+
+    Ok(raw_body)
+}
@@ -0,0 +1,28 @@
+//! Fixtures shared by the integration tests
+
+use std::time::Duration;
+
+use uber_api::{CreateDeliveryRequest, RetryPolicy, UberClient};
+use wiremock::MockServer;
+
+/// A delivery in Manhattan, told apart from others by its `manifest_reference`
+pub fn delivery_request(reference: &str) -> CreateDeliveryRequest {
+    CreateDeliveryRequest {
+        pickup_address: "20 W 34th St, New York, NY 10001".into(),
+        dropoff_address: "285 Fulton St, New York, NY 10007".into(),
+        manifest_reference: Some(reference.to_string()),
+        ..Default::default()
+    }
+}
+
+/// A client for customer `cus_123` calling `server`
+pub fn mock_client(server: &MockServer) -> UberClient {
+    UberClient::new("cus_123").with_api_base_url(server.uri())
+}
+
+/// Retries with backoffs of a few milliseconds, Retry-After is clamped to 50ms
+pub fn fast_policy(max_attempts: u32) -> RetryPolicy {
+    RetryPolicy::new(max_attempts)
+        .with_initial_backoff(Duration::from_millis(10))
+        .with_max_backoff(Duration::from_millis(50))
+}
@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod bulk_create_tests {
+    use std::time::{Duration, Instant};
+
+    use uber_api::CreateDeliveryRequest;
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use crate::common::{delivery_request, fast_policy, mock_client};
+
+    const DELIVERIES_PATH: &str = "/v1/customers/cus_123/deliveries";
+
+    fn requests(count: usize) -> Vec<CreateDeliveryRequest> {
+        (0..count).map(|i| delivery_request(&format!("order-{}", i))).collect()
+    }
+
+    async fn mount_created(server: &MockServer, reference: &str) {
+        Mock::given(method("POST"))
+            .and(path(DELIVERIES_PATH))
+            .and(body_partial_json(serde_json::json!({ "manifest_reference": reference })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "id": format!("del_{}", reference) })))
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_report_keeps_request_order() {
+        let server = MockServer::start().await;
+        for i in 0..12 {
+            mount_created(&server, &format!("order-{}", i)).await;
+        }
+
+        let report = mock_client(&server)
+            .create_deliveries_bulk("token_123", requests(12))
+            .with_concurrency(4)
+            .run()
+            .await;
+
+        assert!(report.all_created());
+        assert_eq!(report.created_count(), 12);
+        for (i, item) in report.items.iter().enumerate() {
+            assert_eq!(item.index, i);
+            assert_eq!(item.delivery_id(), Some(format!("del_order-{}", i).as_str()));
+            assert_eq!(item.retries, 0);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_pauses_the_whole_batch() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path(DELIVERIES_PATH))
+            .respond_with(ResponseTemplate::new(429)
+                .insert_header("Retry-After", "1")
+                .set_body_json(serde_json::json!({ "code": "customer_limited", "message": "Too many requests" })))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        for i in 0..3 {
+            mount_created(&server, &format!("order-{}", i)).await;
+        }
+
+        let started = Instant::now();
+        let report = mock_client(&server)
+            .create_deliveries_bulk("token_123", requests(3))
+            .with_concurrency(1)
+            .with_retry_policy(fast_policy(3).with_max_backoff(Duration::from_secs(2)))
+            .run()
+            .await;
+
+        // the items after the rate limited one waited for Retry-After too
+        assert!(started.elapsed() >= Duration::from_secs(1));
+        assert!(report.all_created());
+        assert_eq!(report.rate_limited, 1);
+        assert_eq!(report.items[0].retries, 1);
+        assert_eq!(report.items.iter().map(|item| item.retries).sum::<u32>(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_server_errors_only_retried_with_idempotency_key() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path(DELIVERIES_PATH))
+            .respond_with(ResponseTemplate::new(503).set_body_json(serde_json::json!({ "code": "service_unavailable", "message": "down" })))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&server)
+            .await;
+        mount_created(&server, "order-0").await;
+
+        let mut keyed = delivery_request("order-0");
+        keyed.idempotency_key = Some("key-0".to_string());
+
+        let report = mock_client(&server)
+            .create_deliveries_bulk("token_123", vec![delivery_request("order-0")])
+            .with_retry_policy(fast_policy(3))
+            .run()
+            .await;
+        assert_eq!(report.failed_count(), 1);
+        assert_eq!(report.items[0].retries, 0);
+        assert_eq!(report.items[0].error_code().as_deref(), Some("service_unavailable"));
+
+        let report = mock_client(&server)
+            .create_deliveries_bulk("token_123", vec![keyed])
+            .with_retry_policy(fast_policy(3))
+            .run()
+            .await;
+        assert!(report.all_created());
+        assert_eq!(report.items[0].retries, 1);
+    }
+
+    #[tokio::test]
+    async fn test_failures_are_reported_per_item() {
+        let server = MockServer::start().await;
+        mount_created(&server, "order-0").await;
+        Mock::given(method("POST"))
+            .and(path(DELIVERIES_PATH))
+            .and(body_partial_json(serde_json::json!({ "manifest_reference": "order-1" })))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "code": "address_undeliverable",
+                "message": "The specified location is not in a deliverable area.",
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let report = mock_client(&server).create_deliveries_bulk("token_123", requests(2)).run().await;
+
+        assert_eq!(report.created_count(), 1);
+        let failed: Vec<_> = report.failed().collect();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].manifest_reference.as_deref(), Some("order-1"));
+        assert_eq!(failed[0].error_code().as_deref(), Some("address_undeliverable"));
+        assert_eq!(failed[0].delivery_id(), None);
+    }
+}
@@ -0,0 +1,200 @@
+#[cfg(test)]
+mod client_tests {
+    use reqwest::StatusCode;
+    use uber_api::{AuthRequest, CreateQuoteRequest, DeliveryFilter, DeliveryState, ImageFormat, ListDeliveriesQuery, PodType, PodWaypoint, UberClient, UberError, UberErrorCode};
+    use wiremock::matchers::{body_json, body_string_contains, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use crate::common::mock_client;
+
+    fn delivery_json(id: &str, status: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "kind": "delivery",
+            "status": status,
+            "complete": false,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_auth_uses_auth_base_url() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/oauth/v2/token"))
+            .and(body_string_contains("grant_type=client_credentials"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "token_123",
+                "expires_in": 2592000,
+                "token_type": "Bearer",
+                "scope": "eats.deliveries",
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = UberClient::new("cus_123").with_auth_base_url(server.uri());
+        let auth_response = client.auth(AuthRequest::new("client_id", "client_secret")).await.unwrap().into_data();
+
+        assert_eq!(auth_response.access_token, "token_123");
+        assert_eq!(auth_response.expires_in, 2592000);
+    }
+
+    #[tokio::test]
+    async fn test_get_delivery_uses_api_base_url_and_customer_id() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/customers/cus_123/deliveries/del_abc"))
+            .and(header("authorization", "Bearer token_123"))
+            .respond_with(ResponseTemplate::new(200)
+                .insert_header("x-uber-request-id", "req_789")
+                .set_body_json(delivery_json("del_abc", "pending")))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = UberClient::new("cus_123").with_api_base_url(format!("{}/", server.uri()));
+        let response = client.get_delivery("token_123", "del_abc").await.unwrap();
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.request_id.as_deref(), Some("req_789"));
+        assert!(response.headers.contains_key("content-type"));
+        assert!(response.raw_body.contains("del_abc"));
+        assert_eq!(response.data.id.as_deref(), Some("del_abc"));
+        assert_eq!(response.data.status, Some(DeliveryState::Pending));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_delivery_parses_canceled_delivery() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/customers/cus_123/deliveries/del_abc/cancel"))
+            .respond_with(ResponseTemplate::new(200)
+                .insert_header("x-request-id", "req_456")
+                .set_body_json(delivery_json("del_abc", "canceled")))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let response = client.cancel_delivery("token_123", "del_abc").await.unwrap();
+
+        assert_eq!(response.request_id.as_deref(), Some("req_456"));
+        assert_eq!(response.data.status, Some(DeliveryState::Canceled));
+    }
+
+    #[tokio::test]
+    async fn test_list_deliveries_builds_query_string() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/customers/cus_123/deliveries"))
+            .and(query_param("filter", "ongoing"))
+            .and(query_param("limit", "5"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [delivery_json("del_abc", "pickup")],
+                "object": "list",
+                "total_count": -1,
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let query = ListDeliveriesQuery::new().with_filter(DeliveryFilter::Ongoing).with_limit(5);
+        let list = client.list_deliveries("token_123", &query).await.unwrap().into_data();
+
+        assert_eq!(list.data.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_clones_share_configuration() {
+        let client = UberClient::new("cus_123")
+            .with_api_base_url("http://127.0.0.1:9000/")
+            .with_auth_base_url("http://127.0.0.1:9001");
+        let cloned = client.clone();
+
+        assert_eq!(cloned.customer_id(), "cus_123");
+        assert_eq!(cloned.api_base_url(), "http://127.0.0.1:9000");
+        assert_eq!(cloned.auth_base_url(), "http://127.0.0.1:9001");
+        assert_eq!(UberClient::default().api_base_url(), "https://api.uber.com");
+    }
+
+    #[tokio::test]
+    async fn test_error_envelope_becomes_api_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/customers/cus_123/delivery_quotes"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "code": "pickup_window_too_small",
+                "message": "The pickup window needs to be at least 10 minutes long.",
+                "metadata": { "pickup_ready_dt": "2023-02-09T09:29:27Z" },
+            })))
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let request = CreateQuoteRequest::new("pickup".to_string(), "dropoff".to_string());
+        let err = client.create_quote("token_123", request).await.unwrap_err();
+
+        match err {
+            UberError::Api { status, code, message, metadata } => {
+                assert_eq!(status, StatusCode::BAD_REQUEST);
+                assert_eq!(code, UberErrorCode::PickupWindowTooSmall);
+                assert!(code.is_time_window_problem());
+                assert_eq!(message, "The pickup window needs to be at least 10 minutes long.");
+                assert_eq!(metadata.unwrap()["pickup_ready_dt"], "2023-02-09T09:29:27Z");
+            }
+            other => panic!("expected UberError::Api, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_oauth_error_and_plain_text_bodies() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/oauth/v2/token"))
+            .respond_with(ResponseTemplate::new(401).set_body_json(serde_json::json!({
+                "error": "invalid_client",
+                "error_description": "The client ID or secret provided is invalid.",
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/customers/cus_123/deliveries/del_abc"))
+            .respond_with(ResponseTemplate::new(503).set_body_string("upstream connect error"))
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server)
+            .with_auth_base_url(server.uri());
+
+        let err = client.auth(AuthRequest::new("client_id", "client_secret")).await.unwrap_err();
+        assert!(err.is_unauthorized());
+        assert_eq!(err.get_code(), "invalid_client");
+
+        let err = client.get_delivery("token_123", "del_abc").await.unwrap_err();
+        assert_eq!(err.error_code(), Some(&UberErrorCode::ServiceUnavailable));
+        assert!(err.error_code().unwrap().is_retryable());
+        assert!(err.to_string().contains("upstream connect error"));
+    }
+
+    #[tokio::test]
+    async fn test_proof_of_delivery_is_decoded() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/customers/cus_123/deliveries/del_abc/proof-of-delivery"))
+            .and(body_json(serde_json::json!({ "waypoint": "dropoff", "type": "signature" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                // PNG signature followed by the start of an IHDR chunk
+                "document": "iVBORw0KGgoAAAANSUhEUg==",
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let proof = client.proof_of_delivery("token_123", "del_abc", PodWaypoint::Dropoff, PodType::Signature).await.unwrap();
+
+        assert_eq!(proof.format(), ImageFormat::Png);
+        assert_eq!(&proof.bytes()[1..4], b"PNG");
+    }
+}
\ No newline at end of file
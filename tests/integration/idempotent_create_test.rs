@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod idempotent_create_tests {
+    use uber_api::{CreateDeliveryRequest, UberClient, UberError};
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use crate::common::{delivery_request, mock_client};
+
+    #[tokio::test]
+    async fn test_creates_with_key_as_idempotency_key() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/customers/cus_123/deliveries"))
+            .and(body_partial_json(serde_json::json!({ "idempotency_key": "order-42" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "id": "del_new" })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let outcome = client.create_delivery_idempotent("token_123", delivery_request("order-42")).await.unwrap();
+
+        assert!(!outcome.is_existing());
+        assert_eq!(outcome.delivery_id(), Some("del_new"));
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_delivery_returns_existing_delivery() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/customers/cus_123/deliveries"))
+            .respond_with(ResponseTemplate::new(409).set_body_json(serde_json::json!({
+                "code": "duplicate_delivery",
+                "message": "An active delivery like this already exists.",
+                "metadata": { "delivery_id": "del_existing" },
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/customers/cus_123/deliveries/del_existing"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "del_existing",
+                "status": "pickup",
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let outcome = client.create_delivery_idempotent("token_123", delivery_request("order-42")).await.unwrap();
+
+        assert!(outcome.is_existing());
+        assert_eq!(outcome.delivery_id(), Some("del_existing"));
+    }
+
+    #[tokio::test]
+    async fn test_requires_manifest_reference_or_external_id() {
+        let client = UberClient::new("cus_123").with_api_base_url("http://127.0.0.1:1");
+        let err = client.create_delivery_idempotent("token_123", CreateDeliveryRequest::default()).await.unwrap_err();
+
+        assert!(matches!(err, UberError::BadInput(_)));
+    }
+
+    #[test]
+    fn test_duplicate_delivery_id_from_nested_metadata_and_message() {
+        let nested = UberError::from_response(
+            reqwest::StatusCode::CONFLICT,
+            r#"{"code":"duplicate_delivery","message":"","metadata":{"existing":{"uuid":"abc","ref":"del_nested"}}}"#,
+        );
+        assert_eq!(nested.duplicate_delivery_id().as_deref(), Some("del_nested"));
+
+        let in_message = UberError::from_response(
+            reqwest::StatusCode::CONFLICT,
+            r#"{"code":"duplicate_delivery","message":"Delivery del_from_message already exists."}"#,
+        );
+        assert_eq!(in_message.duplicate_delivery_id().as_deref(), Some("del_from_message"));
+
+        let other = UberError::from_response(reqwest::StatusCode::BAD_REQUEST, r#"{"code":"invalid_params","message":"del_x"}"#);
+        assert_eq!(other.duplicate_delivery_id(), None);
+    }
+}
@@ -0,0 +1,9 @@
+mod bulk_create_test;
+mod client_test;
+mod idempotent_create_test;
+mod paginator_test;
+mod quoted_delivery_test;
+mod retry_test;
+mod token_provider_test;
+mod tracker_test;
+mod webhook_server_test;
\ No newline at end of file
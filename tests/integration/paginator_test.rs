@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod paginator_tests {
+    use futures::TryStreamExt;
+    use uber_api::{DeliveryFilter, ListDeliveriesQuery, UberErrorCode};
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use crate::common::mock_client;
+
+    const DELIVERIES_PATH: &str = "/v1/customers/cus_123/deliveries";
+
+    fn page(ids: &[&str], next_href: Option<String>) -> ResponseTemplate {
+        let data: Vec<_> = ids.iter().map(|id| serde_json::json!({ "id": id })).collect();
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": data,
+            "next_href": next_href,
+            "object": "list",
+            "total_count": -1,
+        }))
+    }
+
+    async fn mount_page(server: &MockServer, offset: &str, response: ResponseTemplate, expected: u64) {
+        Mock::given(method("GET"))
+            .and(path(DELIVERIES_PATH))
+            .and(query_param("offset", offset))
+            .respond_with(response)
+            .expect(expected)
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_collect_all_follows_next_href() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(DELIVERIES_PATH))
+            .and(query_param("filter", "delivered"))
+            .and(query_param("limit", "2"))
+            .respond_with(page(&["del_1", "del_2"], Some(format!("https://api.uber.com{}?limit=2&offset=2", DELIVERIES_PATH))))
+            .expect(1)
+            .mount(&server)
+            .await;
+        mount_page(&server, "2", page(&["del_3", "del_4"], Some(format!("{}?limit=2&offset=4", DELIVERIES_PATH))), 1).await;
+        mount_page(&server, "4", page(&["del_5"], None), 1).await;
+
+        let client = mock_client(&server);
+        let deliveries = client.list_deliveries_paginated("token_123", ListDeliveriesQuery::new().with_filter(DeliveryFilter::Delivered))
+            .with_page_size(2)
+            .collect_all()
+            .await
+            .unwrap();
+
+        let ids: Vec<_> = deliveries.iter().filter_map(|delivery| delivery.id.as_deref()).collect();
+        assert_eq!(ids, ["del_1", "del_2", "del_3", "del_4", "del_5"]);
+    }
+
+    #[tokio::test]
+    async fn test_max_items_stops_without_fetching_more_pages() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(DELIVERIES_PATH))
+            .and(query_param("limit", "2"))
+            .respond_with(page(&["del_1", "del_2"], Some(format!("{}?limit=2&offset=2", DELIVERIES_PATH))))
+            .expect(1)
+            .mount(&server)
+            .await;
+        mount_page(&server, "2", page(&["del_3", "del_4"], None), 0).await;
+
+        let client = mock_client(&server);
+        let deliveries = client.list_deliveries_paginated("token_123", ListDeliveriesQuery::new())
+            .with_page_size(2)
+            .with_max_items(2)
+            .collect_all()
+            .await
+            .unwrap();
+
+        assert_eq!(deliveries.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_stream_yields_error_and_ends() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(DELIVERIES_PATH))
+            .and(query_param("limit", "1"))
+            .respond_with(page(&["del_1"], Some(format!("{}?limit=1&offset=1", DELIVERIES_PATH))))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        mount_page(&server, "1", ResponseTemplate::new(500).set_body_json(serde_json::json!({
+            "code": "unknown_error",
+            "message": "An unknown error happened.",
+        })), 1).await;
+
+        let client = mock_client(&server);
+        let mut deliveries = client.list_deliveries_paginated("token_123", ListDeliveriesQuery::new())
+            .with_page_size(1)
+            .with_prefetch(false)
+            .into_stream();
+
+        assert_eq!(deliveries.try_next().await.unwrap().unwrap().id.as_deref(), Some("del_1"));
+        let err = deliveries.try_next().await.unwrap_err();
+        assert_eq!(err.error_code(), Some(&UberErrorCode::UnknownError));
+        assert!(deliveries.try_next().await.unwrap().is_none());
+    }
+}
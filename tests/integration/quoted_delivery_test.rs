@@ -0,0 +1,214 @@
+#[cfg(test)]
+mod quoted_delivery_tests {
+    use chrono::{Duration, Utc};
+    use uber_api::{Money, UberError, UberErrorCode};
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use crate::common::{delivery_request, mock_client};
+
+    const QUOTES_PATH: &str = "/v1/customers/cus_123/delivery_quotes";
+    const DELIVERIES_PATH: &str = "/v1/customers/cus_123/deliveries";
+
+    fn quote(id: &str, fee: i64, expires_in: Duration) -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": id,
+            "kind": "delivery_quote",
+            "fee": fee,
+            "currency": "usd",
+            "expires": (Utc::now() + expires_in).to_rfc3339(),
+        }))
+    }
+
+    fn delivery(fee: i64) -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({ "id": "del_abc", "fee": fee, "currency": "usd" }))
+    }
+
+    fn uber_error(code: &str) -> ResponseTemplate {
+        ResponseTemplate::new(400).set_body_json(serde_json::json!({ "code": code, "message": code }))
+    }
+
+    async fn mount_quote(server: &MockServer, response: ResponseTemplate, expected: u64) {
+        Mock::given(method("POST"))
+            .and(path(QUOTES_PATH))
+            .respond_with(response)
+            .up_to_n_times(expected)
+            .expect(expected)
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_books_with_quote_id_and_quoted_fee() {
+        let server = MockServer::start().await;
+        mount_quote(&server, quote("dqt_1", 799, Duration::minutes(15)), 1).await;
+        Mock::given(method("POST"))
+            .and(path(DELIVERIES_PATH))
+            .and(body_partial_json(serde_json::json!({ "quote_id": "dqt_1" })))
+            .respond_with(delivery(799))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let flow = mock_client(&server).quoted_delivery("token_123", delivery_request("order-42"));
+        let quote = flow.quote().await.unwrap();
+        assert_eq!(quote.fee, Some(Money::new(799, "USD")));
+
+        let booking = flow.book(quote).await.unwrap();
+        assert_eq!(booking.delivery.id.as_deref(), Some("del_abc"));
+        assert_eq!(booking.requotes, 0);
+    }
+
+    #[tokio::test]
+    async fn test_expired_quote_is_requoted() {
+        let server = MockServer::start().await;
+        mount_quote(&server, quote("dqt_1", 799, Duration::minutes(15)), 1).await;
+        mount_quote(&server, quote("dqt_2", 799, Duration::minutes(15)), 1).await;
+        Mock::given(method("POST"))
+            .and(path(DELIVERIES_PATH))
+            .and(body_partial_json(serde_json::json!({ "quote_id": "dqt_1" })))
+            .respond_with(uber_error("expired_quote"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path(DELIVERIES_PATH))
+            .and(body_partial_json(serde_json::json!({ "quote_id": "dqt_2" })))
+            .respond_with(delivery(799))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let booking = mock_client(&server).quoted_delivery("token_123", delivery_request("order-42")).quote_and_book().await.unwrap();
+
+        assert_eq!(booking.requotes, 1);
+        assert_eq!(booking.quote.id.as_deref(), Some("dqt_2"));
+    }
+
+    async fn mount_deliveries(server: &MockServer, deliveries: serde_json::Value) {
+        Mock::given(method("GET"))
+            .and(path(DELIVERIES_PATH))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "data": deliveries })))
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_used_quote_returns_the_delivery_it_booked() {
+        let server = MockServer::start().await;
+        mount_quote(&server, quote("dqt_1", 799, Duration::minutes(15)), 1).await;
+        Mock::given(method("POST"))
+            .and(path(DELIVERIES_PATH))
+            .respond_with(uber_error("used_quote"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        // an earlier attempt of this create delivery call booked del_abc
+        mount_deliveries(&server, serde_json::json!([
+            { "id": "del_other", "quote_id": "dqt_0", "fee": 999, "currency": "usd" },
+            { "id": "del_abc", "quote_id": "dqt_1", "fee": 799, "currency": "usd" },
+        ])).await;
+
+        let booking = mock_client(&server).quoted_delivery("token_123", delivery_request("order-42")).quote_and_book().await.unwrap();
+
+        assert!(booking.existing);
+        assert_eq!(booking.requotes, 0);
+        assert_eq!(booking.delivery.id.as_deref(), Some("del_abc"));
+    }
+
+    #[tokio::test]
+    async fn test_used_quote_without_a_delivery_is_requoted() {
+        let server = MockServer::start().await;
+        mount_quote(&server, quote("dqt_1", 799, Duration::minutes(15)), 1).await;
+        mount_quote(&server, quote("dqt_2", 799, Duration::minutes(15)), 1).await;
+        Mock::given(method("POST"))
+            .and(path(DELIVERIES_PATH))
+            .and(body_partial_json(serde_json::json!({ "quote_id": "dqt_1" })))
+            .respond_with(uber_error("used_quote"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path(DELIVERIES_PATH))
+            .and(body_partial_json(serde_json::json!({ "quote_id": "dqt_2" })))
+            .respond_with(delivery(799))
+            .expect(1)
+            .mount(&server)
+            .await;
+        mount_deliveries(&server, serde_json::json!([{ "id": "del_other", "quote_id": "dqt_0" }])).await;
+
+        let booking = mock_client(&server).quoted_delivery("token_123", delivery_request("order-42")).quote_and_book().await.unwrap();
+
+        assert!(!booking.existing);
+        assert_eq!(booking.requotes, 1);
+        assert_eq!(booking.quote.id.as_deref(), Some("dqt_2"));
+    }
+
+    #[tokio::test]
+    async fn test_requote_with_another_fee_is_a_mismatch() {
+        let server = MockServer::start().await;
+        mount_quote(&server, quote("dqt_1", 799, Duration::minutes(15)), 1).await;
+        mount_quote(&server, quote("dqt_2", 999, Duration::minutes(15)), 1).await;
+        Mock::given(method("POST"))
+            .and(path(DELIVERIES_PATH))
+            .respond_with(uber_error("expired_quote"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let err = mock_client(&server).quoted_delivery("token_123", delivery_request("order-42")).quote_and_book().await.unwrap_err();
+
+        assert!(matches!(err, UberError::QuoteMismatch { delivery_id: None, .. }), "{:?}", err);
+        assert_eq!(err.get_code(), "mismatched_price_quote");
+    }
+
+    #[tokio::test]
+    async fn test_charged_fee_must_match_quote() {
+        let server = MockServer::start().await;
+        mount_quote(&server, quote("dqt_1", 799, Duration::minutes(15)), 1).await;
+        Mock::given(method("POST"))
+            .and(path(DELIVERIES_PATH))
+            .respond_with(delivery(1099))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let err = mock_client(&server).quoted_delivery("token_123", delivery_request("order-42")).quote_and_book().await.unwrap_err();
+
+        match err {
+            UberError::QuoteMismatch { quote_id, message, delivery_id } => {
+                assert_eq!(quote_id, "dqt_1");
+                assert_eq!(delivery_id.as_deref(), Some("del_abc"));
+                assert!(message.contains("10.99 USD"), "{}", message);
+            }
+            other => panic!("unexpected error {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mismatched_price_quote_from_uber() {
+        let server = MockServer::start().await;
+        mount_quote(&server, quote("dqt_1", 799, Duration::minutes(15)), 1).await;
+        Mock::given(method("POST"))
+            .and(path(DELIVERIES_PATH))
+            .respond_with(uber_error("mismatched_price_quote"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let err = mock_client(&server).quoted_delivery("token_123", delivery_request("order-42")).quote_and_book().await.unwrap_err();
+
+        assert!(matches!(err, UberError::QuoteMismatch { ref quote_id, delivery_id: None, .. } if quote_id == "dqt_1"));
+    }
+
+    #[tokio::test]
+    async fn test_quote_expiring_within_margin_is_rejected() {
+        let server = MockServer::start().await;
+        mount_quote(&server, quote("dqt_1", 799, Duration::seconds(10)), 1).await;
+
+        let err = mock_client(&server).quoted_delivery("token_123", delivery_request("order-42")).quote().await.unwrap_err();
+
+        assert_eq!(err.error_code(), Some(&UberErrorCode::ExpiredQuote));
+    }
+}
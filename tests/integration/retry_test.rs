@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod retry_tests {
+    use std::time::{Duration, Instant};
+
+    use reqwest::StatusCode;
+    use uber_api::{CreateDeliveryRequest, Endpoint, ListDeliveriesQuery, RetryPolicy, UberErrorCode};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use crate::common::{fast_policy, mock_client};
+
+    fn unavailable() -> ResponseTemplate {
+        ResponseTemplate::new(503).set_body_json(serde_json::json!({
+            "code": "service_unavailable",
+            "message": "Service is currently unavailable.",
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_idempotent_call_is_retried_until_success() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/customers/cus_123/deliveries/del_abc"))
+            .respond_with(unavailable())
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/customers/cus_123/deliveries/del_abc"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "id": "del_abc" })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server)
+            .with_retry_policy(fast_policy(3));
+        let delivery = client.get_delivery("token_123", "del_abc").await.unwrap().into_data();
+
+        assert_eq!(delivery.id.as_deref(), Some("del_abc"));
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_max_attempts() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/customers/cus_123/deliveries/del_abc"))
+            .respond_with(unavailable())
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server)
+            .with_retry_policy(fast_policy(3));
+        let err = client.get_delivery("token_123", "del_abc").await.unwrap_err();
+
+        assert_eq!(err.error_code(), Some(&UberErrorCode::ServiceUnavailable));
+    }
+
+    #[tokio::test]
+    async fn test_mutating_call_is_not_retried_unless_marked_safe() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/customers/cus_123/deliveries"))
+            .respond_with(unavailable())
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server)
+            .with_retry_policy(fast_policy(3));
+        assert!(client.create_delivery("token_123", CreateDeliveryRequest::default()).await.is_err());
+        server.verify().await;
+
+        server.reset().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/customers/cus_123/deliveries"))
+            .respond_with(unavailable())
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let client = client.with_retry_policy(fast_policy(3).mark_safe(Endpoint::CreateDelivery));
+        assert!(client.create_delivery("token_123", CreateDeliveryRequest::default()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_header_is_honored() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/customers/cus_123/deliveries"))
+            .respond_with(ResponseTemplate::new(429)
+                .insert_header("Retry-After", "1")
+                .set_body_json(serde_json::json!({ "code": "customer_limited", "message": "" })))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/customers/cus_123/deliveries"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "data": [] })))
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server)
+            .with_retry_policy(fast_policy(3).with_max_backoff(Duration::from_secs(2)));
+        let started = Instant::now();
+        client.list_deliveries("token_123", &ListDeliveriesQuery::new()).await.unwrap();
+
+        assert!(started.elapsed() >= Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_backoff_stays_under_exponential_ceiling() {
+        let policy = RetryPolicy::new(5)
+            .with_initial_backoff(Duration::from_millis(100))
+            .with_max_backoff(Duration::from_millis(300));
+
+        for _ in 0..50 {
+            assert!(policy.backoff(1, None) <= Duration::from_millis(100));
+            assert!(policy.backoff(2, None) <= Duration::from_millis(200));
+            assert!(policy.backoff(6, None) <= Duration::from_millis(300));
+        }
+        assert_eq!(policy.backoff(1, Some(Duration::from_millis(70))), Duration::from_millis(70));
+        assert!(RetryPolicy::is_retryable_status(StatusCode::TOO_MANY_REQUESTS));
+        assert!(!RetryPolicy::is_retryable_status(StatusCode::BAD_REQUEST));
+    }
+
+    #[test]
+    fn test_retry_after_is_clamped_to_max_backoff() {
+        let policy = RetryPolicy::new(3).with_max_backoff(Duration::from_secs(30));
+
+        assert_eq!(policy.backoff(1, Some(Duration::from_secs(86400))), Duration::from_secs(30));
+        assert_eq!(policy.backoff(1, Some(Duration::from_secs(7))), Duration::from_secs(7));
+    }
+}
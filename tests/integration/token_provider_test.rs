@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod token_provider_tests {
+    use std::time::Duration;
+
+    use uber_api::{AuthRequest, TokenProvider};
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use crate::common::mock_client;
+
+    fn token_json(access_token: &str, expires_in: i64) -> serde_json::Value {
+        serde_json::json!({
+            "access_token": access_token,
+            "expires_in": expires_in,
+            "token_type": "Bearer",
+            "scope": "eats.deliveries",
+        })
+    }
+
+    fn token_provider(server: &MockServer) -> TokenProvider {
+        let client = mock_client(server).with_auth_base_url(server.uri());
+        TokenProvider::new(client, AuthRequest::new("client_id", "client_secret"))
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_callers_share_one_refresh() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/oauth/v2/token"))
+            .respond_with(ResponseTemplate::new(200)
+                .set_body_json(token_json("token_1", 2592000))
+                .set_delay(Duration::from_millis(100)))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let tokens = token_provider(&server);
+        let (a, b, c) = tokio::join!(tokens.access_token(), tokens.access_token(), tokens.access_token());
+
+        assert_eq!(a.unwrap(), "token_1");
+        assert_eq!(b.unwrap(), "token_1");
+        assert_eq!(c.unwrap(), "token_1");
+    }
+
+    #[tokio::test]
+    async fn test_refreshes_token_close_to_expiry() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/oauth/v2/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(token_json("token_1", 2)))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        // the margin is cut to half of the 2s lifetime
+        let tokens = token_provider(&server).with_refresh_margin(Duration::from_secs(60));
+        tokens.access_token().await.unwrap();
+        tokens.access_token().await.unwrap();
+        tokio::time::sleep(Duration::from_millis(1100)).await;
+        tokens.access_token().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_short_lived_token_within_the_margin_is_reused() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/oauth/v2/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(token_json("token_1", 30)))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let tokens = token_provider(&server);
+        for _ in 0..3 {
+            assert_eq!(tokens.access_token().await.unwrap(), "token_1");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reauthenticates_once_on_unauthorized() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/oauth/v2/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(token_json("stale_token", 2592000)))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/oauth/v2/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(token_json("fresh_token", 2592000)))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/customers/cus_123/deliveries/del_abc"))
+            .and(header("authorization", "Bearer stale_token"))
+            .respond_with(ResponseTemplate::new(401).set_body_json(serde_json::json!({
+                "code": "unauthorized",
+                "message": "Invalid OAuth 2.0 credentials provided.",
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/customers/cus_123/deliveries/del_abc"))
+            .and(header("authorization", "Bearer fresh_token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "del_abc",
+                "status": "pending",
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let tokens = token_provider(&server);
+        let client = tokens.client().clone();
+        let delivery = tokens.with_token(|access_token| {
+            let client = client.clone();
+            async move { client.get_delivery(&access_token, "del_abc").await }
+        }).await.unwrap().into_data();
+
+        assert_eq!(delivery.id.as_deref(), Some("del_abc"));
+        assert_eq!(tokens.access_token().await.unwrap(), "fresh_token");
+    }
+}
@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod tracker_tests {
+    use std::time::Duration;
+
+    use futures::StreamExt;
+    use uber_api::{DeliveryState, TrackedWaypoint, TrackingEvent, UberError};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use crate::common::mock_client;
+
+    const DELIVERY_PATH: &str = "/v1/customers/cus_123/deliveries/del_abc";
+
+    async fn mount_poll(server: &MockServer, body: serde_json::Value, times: u64) {
+        Mock::given(method("GET"))
+            .and(path(DELIVERY_PATH))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .up_to_n_times(times)
+            .expect(times)
+            .mount(server)
+            .await;
+    }
+
+    fn tracker(server: &MockServer) -> uber_api::DeliveryTracker {
+        mock_client(server)
+            .track_delivery("token_123", "del_abc")
+            .with_pending_interval(Duration::from_millis(10))
+            .with_active_interval(Duration::from_millis(10))
+            .with_imminent_interval(Duration::from_millis(1))
+    }
+
+    #[tokio::test]
+    async fn test_stream_reports_changes_until_terminal() {
+        let server = MockServer::start().await;
+        mount_poll(&server, serde_json::json!({ "id": "del_abc", "status": "pending" }), 1).await;
+        mount_poll(&server, serde_json::json!({
+            "id": "del_abc",
+            "status": "pickup",
+            "pickup_eta": "2024-03-01T12:10:00Z",
+            "courier": { "name": "Sam R.", "location": { "lat": 40.7, "lng": -73.9 } },
+        }), 1).await;
+        mount_poll(&server, serde_json::json!({
+            "id": "del_abc",
+            "status": "pickup",
+            "pickup_eta": "2024-03-01T12:10:00Z",
+            "courier_imminent": true,
+            "courier": { "name": "Sam R.", "location": { "lat": 40.71, "lng": -73.9 } },
+        }), 1).await;
+        mount_poll(&server, serde_json::json!({
+            "id": "del_abc",
+            "status": "delivered",
+            "pickup_eta": "2024-03-01T12:10:00Z",
+            "courier": { "name": "Sam R.", "location": { "lat": 40.71, "lng": -73.9 } },
+        }), 1).await;
+
+        let events: Vec<_> = tracker(&server).into_stream().collect().await;
+        let events: Vec<TrackingEvent> = events.into_iter().map(Result::unwrap).collect();
+
+        assert_eq!(events.len(), 7, "{:?}", events);
+        assert!(matches!(&events[0], TrackingEvent::StatusChanged { from: None, to: DeliveryState::Pending }));
+        assert!(matches!(&events[1], TrackingEvent::StatusChanged { from: Some(DeliveryState::Pending), to: DeliveryState::Pickup }));
+        assert!(matches!(&events[2], TrackingEvent::EtaChanged { waypoint: TrackedWaypoint::Pickup, from: None, to: Some(_) }));
+        assert!(matches!(&events[3], TrackingEvent::CourierAssigned(courier) if courier.name.as_deref() == Some("Sam R.")));
+        assert!(matches!(&events[4], TrackingEvent::CourierLocationUpdated(location) if location.lat == 40.7));
+        assert!(matches!(&events[5], TrackingEvent::CourierLocationUpdated(location) if location.lat == 40.71));
+        assert!(matches!(&events[6], TrackingEvent::StatusChanged { to: DeliveryState::Delivered, .. }));
+    }
+
+    #[tokio::test]
+    async fn test_callback_and_timeout() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(DELIVERY_PATH))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "id": "del_abc", "status": "pending" })))
+            .mount(&server)
+            .await;
+
+        let mut seen = Vec::new();
+        let result = tracker(&server)
+            .with_timeout(Duration::from_millis(50))
+            .run(|event| seen.push(event))
+            .await;
+
+        assert!(matches!(result, Err(UberError::Timeout(_))));
+        // a delivery that stays pending is only reported once
+        assert_eq!(seen.len(), 1);
+        assert!(server.received_requests().await.unwrap().len() > 1);
+    }
+
+    #[tokio::test]
+    async fn test_hanging_poll_times_out_at_the_deadline() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(DELIVERY_PATH))
+            .respond_with(ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "id": "del_abc", "status": "pending" }))
+                .set_delay(Duration::from_secs(10)))
+            .mount(&server)
+            .await;
+
+        let started = std::time::Instant::now();
+        let result = tracker(&server)
+            .with_timeout(Duration::from_millis(50))
+            .run(|_| {})
+            .await;
+
+        assert!(matches!(result, Err(UberError::Timeout(_))));
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn test_polling_error_ends_the_stream() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(DELIVERY_PATH))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "code": "delivery_not_found",
+                "message": "The requested delivery does not exist.",
+            })))
+            .mount(&server)
+            .await;
+
+        let events: Vec<_> = tracker(&server).into_stream().collect().await;
+
+        assert_eq!(events.len(), 1);
+        assert!(events[0].is_err());
+    }
+}
@@ -0,0 +1,153 @@
+#[cfg(all(test, feature = "webhook-server"))]
+mod webhook_server_tests {
+    use std::net::SocketAddr;
+
+    use tokio::sync::{mpsc, oneshot};
+    use tokio::task::JoinHandle;
+    use uber_api::{UberError, WebhookServer};
+    use uber_api::models::webhooks::{read_journal, sign_webhook, DeliveryStatus, ReplayFilter, WebhookHandler, WebhookJournal};
+
+    const SIGNING_KEY: &str = "signing_key";
+    const BODY: &str = r#"{ "id": "evt_1", "kind": "event.delivery_status", "status": "pickup", "delivery_id": "del_1" }"#;
+
+    struct Forward {
+        sender: mpsc::UnboundedSender<String>,
+        fail: bool,
+    }
+
+    impl WebhookHandler for Forward {
+        async fn on_delivery_status(&self, event: DeliveryStatus) -> Result<(), UberError> {
+            if self.fail {
+                return Err(UberError::InternalServerError("handler failed".to_string()));
+            }
+            self.sender.send(event.delivery_id.unwrap_or_default()).unwrap();
+            Ok(())
+        }
+    }
+
+    struct Running {
+        url: String,
+        events: mpsc::UnboundedReceiver<String>,
+        shutdown: oneshot::Sender<()>,
+        task: JoinHandle<Result<(), UberError>>,
+    }
+
+    fn start(fail: bool, body_limit: usize) -> Running {
+        let (sender, events) = mpsc::unbounded_channel();
+        let (shutdown, stopped) = oneshot::channel::<()>();
+
+        let server = WebhookServer::new(SIGNING_KEY, Forward { sender, fail })
+            .with_address(SocketAddr::from(([127, 0, 0, 1], 0)))
+            .with_body_limit(body_limit)
+            .bind()
+            .unwrap();
+        let url = format!("http://{}/webhooks/uber", server.local_addr());
+        let task = tokio::spawn(server.serve_with_shutdown(async { stopped.await.unwrap_or_default() }));
+
+        Running { url, events, shutdown, task }
+    }
+
+    async fn post(url: &str, signature: Option<&str>, body: &str) -> (u16, serde_json::Value) {
+        let mut request = reqwest::Client::new().post(url).body(body.to_string());
+        if let Some(signature) = signature {
+            request = request.header("X-Uber-Signature", signature);
+        }
+        let response = request.send().await.unwrap();
+        (response.status().as_u16(), response.json().await.unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_signed_webhook_is_dispatched_and_acknowledged() {
+        let mut running = start(false, 1024);
+
+        let (status, reply) = post(&running.url, Some(&sign_webhook(SIGNING_KEY, BODY.as_bytes())), BODY).await;
+
+        assert_eq!(status, 200);
+        assert_eq!(reply["success"], true);
+        assert_eq!(running.events.recv().await.as_deref(), Some("del_1"));
+
+        running.shutdown.send(()).unwrap();
+        running.task.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_unsigned_and_forged_webhooks_are_rejected() {
+        let mut running = start(false, 1024);
+
+        let (status, reply) = post(&running.url, None, BODY).await;
+        assert_eq!(status, 401);
+        assert_eq!(reply["success"], false);
+
+        let (status, _) = post(&running.url, Some(&sign_webhook("another_key", BODY.as_bytes())), BODY).await;
+        assert_eq!(status, 401);
+
+        running.shutdown.send(()).unwrap();
+        running.task.await.unwrap().unwrap();
+        assert!(running.events.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_request_errors() {
+        let running = start(false, 64);
+
+        let (status, _) = post(&running.url, Some(&sign_webhook(SIGNING_KEY, BODY.as_bytes())), BODY).await;
+        assert_eq!(status, 413);
+
+        let (status, _) = post(&running.url, Some(&sign_webhook(SIGNING_KEY, b"not json")), "not json").await;
+        assert_eq!(status, 400);
+
+        let (status, _) = post(&running.url.replace("/webhooks/uber", "/other"), None, BODY).await;
+        assert_eq!(status, 404);
+
+        let response = reqwest::get(&running.url).await.unwrap();
+        assert_eq!(response.status().as_u16(), 405);
+
+        running.shutdown.send(()).unwrap();
+        running.task.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_handler_error_asks_uber_to_retry() {
+        let running = start(true, 1024);
+
+        let (status, reply) = post(&running.url, Some(&sign_webhook(SIGNING_KEY, BODY.as_bytes())), BODY).await;
+
+        assert_eq!(status, 500);
+        assert!(reply["message"].as_str().unwrap().contains("handler failed"));
+
+        running.shutdown.send(()).unwrap();
+        running.task.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_verified_webhooks_are_journaled() {
+        let path = std::env::temp_dir().join(format!("uber_api_server_journal_{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let (sender, _events) = mpsc::unbounded_channel();
+        let (shutdown, stopped) = oneshot::channel::<()>();
+
+        let server = WebhookServer::new(SIGNING_KEY, Forward { sender, fail: true })
+            .with_address(SocketAddr::from(([127, 0, 0, 1], 0)))
+            .with_journal(WebhookJournal::open(&path).await.unwrap())
+            .bind()
+            .unwrap();
+        let url = format!("http://{}/webhooks/uber", server.local_addr());
+        let task = tokio::spawn(server.serve_with_shutdown(async { stopped.await.unwrap_or_default() }));
+
+        // journaled even though the handler fails, a forged webhook isn't
+        let (status, _) = post(&url, Some(&sign_webhook(SIGNING_KEY, BODY.as_bytes())), BODY).await;
+        assert_eq!(status, 500);
+        let (status, _) = post(&url, Some(&sign_webhook("another_key", BODY.as_bytes())), BODY).await;
+        assert_eq!(status, 401);
+
+        shutdown.send(()).unwrap();
+        task.await.unwrap().unwrap();
+
+        let entries = read_journal(&path, &ReplayFilter::new()).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].1.body, BODY.as_bytes());
+        assert!(entries[0].1.headers.contains_key("x-uber-signature"));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
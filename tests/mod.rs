@@ -1,2 +1,3 @@
+mod common;
 pub mod integration;
 pub mod unit;
\ No newline at end of file
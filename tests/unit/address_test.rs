@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod address_tests {
+    use uber_api::models::general::{Address, StructuredAddress};
+    use uber_api::{CreateDeliveryRequest, CreateQuoteRequest};
+
+    fn market_street() -> StructuredAddress {
+        StructuredAddress {
+            street_address: vec!["456 Market St".to_string(), "Floor 2".to_string()],
+            city: "San Francisco".to_string(),
+            state: "CA".to_string(),
+            zip_code: "94103".to_string(),
+            country: Some("US".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_free_form_address_is_sent_as_is() {
+        let create_quote_request = CreateQuoteRequest::new("456 Market St, San Francisco, CA, 94103", "123 Main St, San Francisco, CA, 94103");
+        let body = serde_json::to_value(&create_quote_request).unwrap();
+
+        assert_eq!(body["pickup_address"], "456 Market St, San Francisco, CA, 94103");
+    }
+
+    #[test]
+    fn test_structured_address_is_sent_as_json_in_a_string() {
+        let create_delivery_request = CreateDeliveryRequest {
+            pickup_address: market_street().into(),
+            ..Default::default()
+        };
+        let body = serde_json::to_value(&create_delivery_request).unwrap();
+
+        let encoded = body["pickup_address"].as_str().unwrap();
+        let decoded: StructuredAddress = serde_json::from_str(encoded).unwrap();
+        assert_eq!(decoded, market_street());
+    }
+
+    #[test]
+    fn test_round_trips_through_serde() {
+        for address in [Address::from(market_street()), Address::from("123 Main St, San Francisco, CA, 94103")] {
+            let json = serde_json::to_string(&address).unwrap();
+            assert_eq!(serde_json::from_str::<Address>(&json).unwrap(), address);
+        }
+    }
+
+    #[test]
+    fn test_deserializes_response_shapes() {
+        let expected = Address::Structured(market_street());
+
+        let json_in_a_string = r#""{\"street_address_1\":\"456 Market St\",\"street_address_2\":\"Floor 2\",\"city\":\"San Francisco\",\"state\":\"CA\",\"zip_code\":\"94103\",\"country\":\"US\"}""#;
+        assert_eq!(serde_json::from_str::<Address>(json_in_a_string).unwrap(), expected);
+
+        let detailed_address = serde_json::json!({
+            "street_address_1": "456 Market St",
+            "street_address_2": "Floor 2",
+            "city": "San Francisco",
+            "state": "CA",
+            "zip_code": "94103",
+            "country": "US",
+        });
+        assert_eq!(serde_json::from_value::<Address>(detailed_address).unwrap(), expected);
+
+        assert!(serde_json::from_value::<Address>(serde_json::json!(42)).is_err());
+    }
+
+    #[test]
+    fn test_unparseable_braces_stay_free_form() {
+        assert_eq!(Address::parse("{Unit 4} Main St"), Address::FreeForm("{Unit 4} Main St".to_string()));
+    }
+}
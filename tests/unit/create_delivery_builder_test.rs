@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod create_delivery_builder_tests {
+    use uber_api::models::create_delivery::CreateDeliveryRequestBuilder;
+    use uber_api::models::general::{IdentificationRequirement, ManifestItem, SignatureRequirement, VerificationRequirement};
+    use uber_api::{CreateDeliveryRequest, UberError};
+
+    fn valid_builder() -> CreateDeliveryRequestBuilder {
+        CreateDeliveryRequest::builder()
+            .with_pickup("Pickup Location", "456 Market St, San Francisco, CA, 94103", "+15555555555")
+            .with_dropoff("Dropoff Location", "123 Main St, San Francisco, CA, 94103", "+15555555555")
+            .with_manifest_item(ManifestItem::new("Robin", 1, "small"))
+    }
+
+    fn bad_input_message(builder: CreateDeliveryRequestBuilder) -> String {
+        match builder.build() {
+            Err(UberError::BadInput(message)) => message,
+            other => panic!("expected BadInput, got {:?}", other),
+        }
+    }
+
+    fn signature_required() -> VerificationRequirement {
+        VerificationRequirement {
+            signature_requirement: Some(SignatureRequirement {
+                enabled: true,
+                collect_signer_name: true,
+                collect_signer_relationship: false,
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_valid_request_builds() {
+        let request = valid_builder()
+            .with_dropoff_notes("Ring the bell")
+            .with_deliverable_action("deliverable_action_leave_at_door")
+            .with_manifest_reference("order_42")
+            .build()
+            .unwrap();
+
+        assert_eq!(request.pickup_name, "Pickup Location");
+        assert_eq!(request.manifest_items.len(), 1);
+        assert_eq!(request.manifest_reference.as_deref(), Some("order_42"));
+    }
+
+    #[test]
+    fn test_required_fields_are_named() {
+        assert_eq!(bad_input_message(CreateDeliveryRequest::builder()), "pickup_name: is required");
+
+        let no_items = CreateDeliveryRequest::builder()
+            .with_pickup("Pickup", "456 Market St", "+15555555555")
+            .with_dropoff("Dropoff", "123 Main St", "+15555555555");
+        assert_eq!(bad_input_message(no_items), "manifest_items: must contain at least one item");
+    }
+
+    #[test]
+    fn test_manifest_item_path_includes_index() {
+        let builder = valid_builder().with_manifest_item(ManifestItem::new("Milk", 0, "small"));
+        assert_eq!(bad_input_message(builder), "manifest_items[1].quantity: must be at least 1");
+
+        let builder = valid_builder().with_manifest_item(ManifestItem::new("Sofa", 1, "huge"));
+        assert_eq!(bad_input_message(builder), "manifest_items[1].size: must be one of small, medium, large, xlarge, big (got 'huge')");
+    }
+
+    #[test]
+    fn test_notes_longer_than_280_characters_are_rejected() {
+        let builder = valid_builder().with_dropoff_notes("x".repeat(281));
+        assert_eq!(bad_input_message(builder), "dropoff_notes: must be at most 280 characters (got 281)");
+
+        assert!(valid_builder().with_pickup_notes("é".repeat(280)).build().is_ok());
+    }
+
+    #[test]
+    fn test_leave_at_door_conflicts_with_signature_or_id() {
+        let builder = valid_builder()
+            .with_deliverable_action("deliverable_action_leave_at_door")
+            .with_dropoff_verification(signature_required());
+        assert_eq!(
+            bad_input_message(builder),
+            "deliverable_action: can't leave at door when dropoff_verification.signature_requirement is required"
+        );
+
+        let builder = valid_builder()
+            .with_undeliverable_action("leave_at_door")
+            .with_dropoff_verification(VerificationRequirement {
+                identification: Some(IdentificationRequirement { min_age: 21 }),
+                ..Default::default()
+            });
+        assert!(bad_input_message(builder).starts_with("undeliverable_action:"));
+
+        assert!(valid_builder()
+            .with_undeliverable_action("return")
+            .with_dropoff_verification(signature_required())
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_unknown_actions_and_coordinates_are_rejected() {
+        let builder = valid_builder().with_deliverable_action("throw_over_fence");
+        assert!(bad_input_message(builder).starts_with("deliverable_action: must be"));
+
+        let builder = valid_builder().with_pickup_coordinates(37.77, -222.0);
+        assert_eq!(bad_input_message(builder), "pickup_longitude: must be between -180 and 180 (got -222)");
+    }
+}
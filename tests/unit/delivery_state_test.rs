@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod delivery_state_tests {
+    use uber_api::{DeliveryState, UberError};
+
+    #[test]
+    fn test_parses_documented_statuses_and_keeps_unknown() {
+        let state: DeliveryState = serde_json::from_str("\"pickup_complete\"").unwrap();
+        assert_eq!(state, DeliveryState::PickupComplete);
+
+        let state: DeliveryState = serde_json::from_str("\"scheduled\"").unwrap();
+        assert_eq!(state, DeliveryState::Unknown("scheduled".to_string()));
+        assert_eq!(serde_json::to_string(&state).unwrap(), "\"scheduled\"");
+    }
+
+    #[test]
+    fn test_terminal_and_active() {
+        assert!(DeliveryState::Delivered.is_terminal());
+        assert!(DeliveryState::Canceled.is_terminal());
+        assert!(DeliveryState::Returned.is_terminal());
+        assert!(!DeliveryState::Dropoff.is_terminal());
+
+        assert!(DeliveryState::Pending.is_active());
+        assert!(DeliveryState::Dropoff.is_active());
+        assert!(!DeliveryState::Delivered.is_active());
+        assert!(!DeliveryState::Unknown("scheduled".to_string()).is_active());
+    }
+
+    #[test]
+    fn test_forward_transitions_are_allowed() {
+        assert!(DeliveryState::Pending.can_transition_to(&DeliveryState::Pickup));
+        // a missed webhook skips states
+        assert!(DeliveryState::Pending.can_transition_to(&DeliveryState::Delivered));
+        assert!(DeliveryState::Pickup.can_transition_to(&DeliveryState::Pending));
+        assert!(DeliveryState::Dropoff.can_transition_to(&DeliveryState::Returned));
+        assert!(DeliveryState::Pickup.can_transition_to(&DeliveryState::Canceled));
+        assert!(DeliveryState::Delivered.can_transition_to(&DeliveryState::Delivered));
+    }
+
+    #[test]
+    fn test_illegal_transitions_are_flagged() {
+        assert!(!DeliveryState::Delivered.can_transition_to(&DeliveryState::Dropoff));
+        assert!(!DeliveryState::Canceled.can_transition_to(&DeliveryState::Pending));
+        assert!(!DeliveryState::Dropoff.can_transition_to(&DeliveryState::Pickup));
+        assert!(!DeliveryState::Pending.can_transition_to(&DeliveryState::Returned));
+
+        let err = DeliveryState::Delivered.validate_transition(&DeliveryState::Pickup).unwrap_err();
+        assert!(matches!(err, UberError::InvalidState(message) if message == "delivery can't go from delivered to pickup"));
+    }
+}
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod delivery_tests {
+    use uber_api::{Delivery, DeliveryState, Money};
+    use uber_api::models::webhooks::DeliveryStatus;
+
+    const ENDPOINT_DELIVERY: &str = r#"{
+        "id": "del_aBc123",
+        "status": "pickup",
+        "complete": false,
+        "currency": "usd",
+        "fee": 799,
+        "tip": 300,
+        "external_id": "order-42",
+        "pickup": {
+            "name": "Store",
+            "phone_number": "+15555555555",
+            "address": "20 W 34th St, New York, NY 10001",
+            "detailed_address": { "street_address_1": "20 W 34th St", "city": "New York", "state": "NY", "zip_code": "10001" },
+            "location": { "lat": 40.74, "lng": -73.98 },
+            "seller_notes": "Back door"
+        },
+        "related_deliveries": [{ "id": "ret_aBc123", "relationship": "returned_by" }]
+    }"#;
+
+    #[test]
+    fn test_endpoint_delivery() {
+        let delivery: Delivery = serde_json::from_str(ENDPOINT_DELIVERY).unwrap();
+
+        assert_eq!(delivery.id.as_deref(), Some("del_aBc123"));
+        assert_eq!(delivery.status, Some(DeliveryState::Pickup));
+        assert_eq!(delivery.fee, Some(Money::new(799, "USD")));
+
+        let pickup = delivery.pickup.unwrap();
+        assert_eq!(pickup.seller_notes.as_deref(), Some("Back door"));
+        assert_eq!(pickup.location.unwrap().lat, 40.74);
+        assert_eq!(delivery.related_deliveries.unwrap()[0].id, "ret_aBc123");
+    }
+
+    #[test]
+    fn test_single_related_delivery_is_accepted() {
+        let delivery: Delivery = serde_json::from_str(r#"{
+            "id": "del_aBc123",
+            "related_deliveries": { "id": "ret_aBc123", "relationship": "returned_by" }
+        }"#).unwrap();
+
+        let related = delivery.related_deliveries.unwrap();
+        assert_eq!(related.len(), 1);
+        assert_eq!(related[0].relationship, "returned_by");
+    }
+
+    #[test]
+    fn test_webhook_embeds_the_same_delivery() {
+        let event: DeliveryStatus = serde_json::from_str(r#"{
+            "status": "delivered",
+            "kind": "event.delivery_status",
+            "delivery_id": "del_aBc123",
+            "data": {
+                "id": "del_aBc123",
+                "status": "delivered",
+                "route_id": "rte_1",
+                "order": { "id": "ord_1", "number": "123", "display_name": "Jane D." },
+                "dropoff": { "name": "Customer", "courier_notes": "Left with doorman" },
+                "return": { "name": "Store" }
+            }
+        }"#).unwrap();
+
+        let delivery = event.data.unwrap();
+        assert_eq!(delivery.status, Some(DeliveryState::Delivered));
+        assert_eq!(delivery.route_id.as_deref(), Some("rte_1"));
+        assert_eq!(delivery.order.unwrap().number.as_deref(), Some("123"));
+        assert_eq!(delivery.dropoff.unwrap().courier_notes.as_deref(), Some("Left with doorman"));
+        assert_eq!(delivery.return_waypoint.unwrap().name.as_deref(), Some("Store"));
+    }
+}
@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod delivery_windows_tests {
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+    use uber_api::models::general::Timestamp;
+    use uber_api::{CreateDeliveryRequest, CreateQuoteRequest, DeliveryWindows, FixedClock, UberError, UberErrorCode};
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap()
+    }
+
+    fn minutes(minutes: i64) -> Option<DateTime<Utc>> {
+        Some(now() + Duration::minutes(minutes))
+    }
+
+    fn valid_windows() -> DeliveryWindows {
+        DeliveryWindows {
+            pickup_ready: minutes(10),
+            pickup_deadline: minutes(40),
+            dropoff_ready: minutes(40),
+            dropoff_deadline: minutes(90),
+        }
+    }
+
+    fn violations(windows: DeliveryWindows) -> Vec<UberErrorCode> {
+        windows.violations(&FixedClock(now()))
+    }
+
+    #[test]
+    fn test_valid_and_empty_windows_pass() {
+        assert!(valid_windows().validate_with(&FixedClock(now())).is_ok());
+        assert!(DeliveryWindows::default().validate_with(&FixedClock(now())).is_ok());
+    }
+
+    #[test]
+    fn test_pickup_ready_rules() {
+        let windows = DeliveryWindows { pickup_ready: None, ..valid_windows() };
+        assert_eq!(violations(windows), [UberErrorCode::PickupReadyTimeNotSpecified]);
+
+        let windows = DeliveryWindows { pickup_ready: minutes(-2), ..valid_windows() };
+        assert_eq!(violations(windows), [UberErrorCode::PickupReadyTooEarly]);
+
+        // "ready now", built a moment before validation runs
+        let windows = DeliveryWindows { pickup_ready: Some(now()), ..valid_windows() };
+        assert!(violations(windows).is_empty());
+        let windows = DeliveryWindows { pickup_ready: Some(now() - Duration::seconds(30)), ..valid_windows() };
+        assert!(violations(windows).is_empty());
+
+        let windows = DeliveryWindows {
+            pickup_ready: minutes(30 * 24 * 60),
+            pickup_deadline: None,
+            dropoff_ready: None,
+            dropoff_deadline: None,
+        };
+        assert_eq!(violations(windows), [UberErrorCode::PickupReadyTooLate]);
+    }
+
+    #[test]
+    fn test_pickup_ready_now_passes_the_system_clock() {
+        let request = CreateDeliveryRequest {
+            pickup_ready_dt: Some(Timestamp::now()),
+            ..Default::default()
+        };
+
+        assert!(DeliveryWindows::from(&request).validate().is_ok());
+    }
+
+    #[test]
+    fn test_pickup_deadline_rules() {
+        let windows = DeliveryWindows { pickup_ready: minutes(5), pickup_deadline: minutes(19), dropoff_ready: minutes(19), ..valid_windows() };
+        assert_eq!(violations(windows), [UberErrorCode::PickupDeadlineTooEarly]);
+
+        let windows = DeliveryWindows { pickup_ready: minutes(35), ..valid_windows() };
+        assert_eq!(violations(windows), [UberErrorCode::PickupWindowTooSmall]);
+    }
+
+    #[test]
+    fn test_dropoff_rules() {
+        let windows = DeliveryWindows { dropoff_ready: minutes(41), ..valid_windows() };
+        assert_eq!(violations(windows), [UberErrorCode::DropoffReadyAfterPickupDeadline]);
+
+        let windows = DeliveryWindows { dropoff_deadline: minutes(59), ..valid_windows() };
+        assert_eq!(violations(windows), [UberErrorCode::DropoffDeadlineTooEarly]);
+
+        let windows = DeliveryWindows { dropoff_ready: minutes(10), dropoff_deadline: minutes(35), ..valid_windows() };
+        assert_eq!(violations(windows), [UberErrorCode::DropoffDeadlineBeforePickupDeadline]);
+    }
+
+    #[test]
+    fn test_validation_error_carries_documented_code() {
+        let windows = DeliveryWindows { pickup_deadline: minutes(15), ..valid_windows() };
+        let err = windows.validate_with(&FixedClock(now())).unwrap_err();
+
+        assert_eq!(err.error_code(), Some(&UberErrorCode::PickupWindowTooSmall));
+        assert!(matches!(&err, UberError::Validation { message, .. } if message == "The pickup window needs to be at least 10 minutes long."));
+    }
+
+    #[test]
+    fn test_quote_and_delivery_requests_share_the_rules() {
+        let timestamp = |minutes: i64| Some(Timestamp::from(now() + Duration::minutes(minutes)));
+
+        let mut create_quote_request = CreateQuoteRequest::new("456 Market St".to_string(), "123 Main St".to_string());
+        create_quote_request.pickup_ready_dt = timestamp(10);
+        create_quote_request.pickup_deadline_dt = timestamp(15);
+        let err = create_quote_request.validate_windows(&FixedClock(now())).unwrap_err();
+        assert_eq!(err.error_code(), Some(&UberErrorCode::PickupWindowTooSmall));
+
+        let create_delivery_request = CreateDeliveryRequest {
+            pickup_ready_dt: timestamp(10),
+            pickup_deadline_dt: timestamp(40),
+            dropoff_ready_dt: timestamp(40),
+            dropoff_deadline_dt: timestamp(50),
+            ..Default::default()
+        };
+        let err = create_delivery_request.validate_windows(&FixedClock(now())).unwrap_err();
+        assert_eq!(err.error_code(), Some(&UberErrorCode::DropoffDeadlineTooEarly));
+    }
+}
@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod error_code_tests {
+    use reqwest::StatusCode;
+    use uber_api::{UberError, UberErrorCode};
+
+    #[test]
+    fn test_documented_codes_round_trip() {
+        for code in [
+            "invalid_params",
+            "unknown_location",
+            "address_undeliverable",
+            "pickup_window_too_small",
+            "expired_quote",
+            "used_quote",
+            "mismatched_price_quote",
+            "customer_suspended",
+            "customer_limited",
+            "delivery_not_found",
+            "duplicate_delivery",
+            "service_unavailable",
+        ] {
+            let error_code = UberErrorCode::from(code);
+            assert!(!matches!(error_code, UberErrorCode::Unknown(_)), "{} should be a known code", code);
+            assert_eq!(error_code.as_str(), code);
+            assert!(error_code.description().is_some());
+        }
+    }
+
+    #[test]
+    fn test_unknown_code_is_kept() {
+        let error_code = UberErrorCode::from("brand_new_code");
+        assert_eq!(error_code, UberErrorCode::Unknown("brand_new_code".to_string()));
+        assert_eq!(error_code.to_string(), "brand_new_code");
+        assert_eq!(error_code.description(), None);
+    }
+
+    #[test]
+    fn test_customer_not_cound_typo_is_customer_not_found() {
+        assert_eq!(UberErrorCode::from("customer_not_cound"), UberErrorCode::CustomerNotFound);
+    }
+
+    #[test]
+    fn test_classification() {
+        assert!(UberErrorCode::CustomerLimited.is_retryable());
+        assert!(UberErrorCode::RequestTimeout.is_retryable());
+        assert!(!UberErrorCode::InvalidParams.is_retryable());
+
+        assert!(UberErrorCode::ExpiredQuote.is_quote_problem());
+        assert!(UberErrorCode::UsedQuote.is_quote_problem());
+        assert!(!UberErrorCode::AddressUndeliverable.is_quote_problem());
+
+        assert!(UberErrorCode::UnknownLocation.is_address_problem());
+        assert!(UberErrorCode::AddressUndeliverableLimitedCouriers.is_address_problem());
+        assert!(!UberErrorCode::CustomerBlocked.is_address_problem());
+
+        assert!(UberErrorCode::DropoffReadyAfterPickupDeadline.is_time_window_problem());
+        assert!(UberErrorCode::CustomerSuspended.is_account_problem());
+        assert!(UberErrorCode::InvalidClient.is_auth_problem());
+    }
+
+    #[test]
+    fn test_plain_text_error_body_gets_the_code_for_its_status() {
+        let err = UberError::from_response(StatusCode::INTERNAL_SERVER_ERROR, "<html>Internal Server Error</html>");
+        assert_eq!(err.error_code(), Some(&UberErrorCode::UnknownError));
+        assert!(err.error_code().unwrap().is_retryable());
+        assert!(matches!(err, UberError::Api { ref message, .. } if message == "<html>Internal Server Error</html>"));
+
+        assert_eq!(UberErrorCode::from_status(StatusCode::REQUEST_TIMEOUT), UberErrorCode::RequestTimeout);
+        assert_eq!(UberErrorCode::from_status(StatusCode::TOO_MANY_REQUESTS), UberErrorCode::CustomerLimited);
+        assert_eq!(UberErrorCode::from_status(StatusCode::SERVICE_UNAVAILABLE), UberErrorCode::ServiceUnavailable);
+        assert_eq!(UberErrorCode::from_status(StatusCode::BAD_GATEWAY), UberErrorCode::Unknown("502".to_string()));
+    }
+
+    #[test]
+    fn test_serde_as_plain_string() {
+        let error_code: UberErrorCode = serde_json::from_str("\"used_quote\"").unwrap();
+        assert_eq!(error_code, UberErrorCode::UsedQuote);
+        assert_eq!(serde_json::to_string(&UberErrorCode::Unknown("x".to_string())).unwrap(), "\"x\"");
+    }
+}
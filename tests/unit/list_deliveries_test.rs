@@ -0,0 +1,35 @@
+#[cfg(test)]
+mod list_deliveries_tests {
+    use uber_api::{DeliveryFilter, ListDeliveriesQuery, UberError};
+
+    #[test]
+    fn test_empty_query_encodes_to_nothing() {
+        assert_eq!(ListDeliveriesQuery::new().to_query_string().unwrap(), "");
+    }
+
+    #[test]
+    fn test_query_is_url_encoded() {
+        let query = ListDeliveriesQuery::new()
+            .with_filter(DeliveryFilter::PickupComplete)
+            .with_limit(25)
+            .with_offset(50);
+
+        assert_eq!(query.to_query_string().unwrap(), "filter=pickup_complete&limit=25&offset=50");
+    }
+
+    #[test]
+    fn test_filter_parses_documented_values() {
+        assert_eq!("ongoing".parse::<DeliveryFilter>().unwrap(), DeliveryFilter::Ongoing);
+        assert_eq!("canceled".parse::<DeliveryFilter>().unwrap().to_string(), "canceled");
+        assert!(matches!("done&limit=1".parse::<DeliveryFilter>(), Err(UberError::BadInput(_))));
+    }
+
+    #[test]
+    fn test_invalid_combinations_are_rejected() {
+        let zero_limit = ListDeliveriesQuery::new().with_limit(0);
+        assert!(matches!(zero_limit.validate(), Err(UberError::BadInput(message)) if message.starts_with("limit")));
+
+        let offset_without_limit = ListDeliveriesQuery::new().with_offset(10);
+        assert!(matches!(offset_without_limit.to_query_string(), Err(UberError::BadInput(message)) if message.starts_with("offset")));
+    }
+}
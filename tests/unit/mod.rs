@@ -1,5 +1,16 @@
+mod address_test;
 mod cancel_delivery_test;
+mod create_delivery_builder_test;
 mod create_delivery_test;
-
-pub use cancel_delivery_test::*;
-pub use create_delivery_test::*;
\ No newline at end of file
+mod delivery_state_test;
+mod delivery_test;
+mod delivery_windows_test;
+mod error_code_test;
+mod list_deliveries_test;
+mod money_test;
+mod pod_retrieval_test;
+mod timestamp_test;
+mod webhook_dedup_test;
+mod webhook_event_test;
+mod webhook_journal_test;
+mod webhook_signature_test;
\ No newline at end of file
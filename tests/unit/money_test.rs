@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod money_tests {
+    use uber_api::{CreateDeliveryRequest, CreateQuoteResponse, GetDeliveryResponse, Money, UberError, UpdateDeliveryRequest};
+    use uber_api::models::general::ManifestItem;
+    use uber_api::models::order::Charges;
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let fee = Money::new(599, "usd");
+        let tip = Money::new(200, "USD");
+
+        assert_eq!(fee.currency(), "USD");
+        assert_eq!(fee.checked_add(&tip).unwrap(), Money::new(799, "USD"));
+        assert_eq!(fee.checked_sub(&tip).unwrap(), Money::new(399, "USD"));
+        assert_eq!(tip.checked_mul(3).unwrap(), Money::new(600, "USD"));
+        assert_eq!(Money::checked_sum("USD", [&fee, &tip, &tip]).unwrap(), Money::new(999, "USD"));
+
+        let overflow = Money::new(i64::MAX, "USD").checked_add(&Money::new(1, "USD"));
+        assert!(matches!(overflow, Err(UberError::InvalidState(_))));
+    }
+
+    #[test]
+    fn test_currency_mismatch() {
+        let err = Money::new(100, "USD").checked_add(&Money::new(100, "CAD")).unwrap_err();
+        match err {
+            UberError::CurrencyMismatch { expected, found } => {
+                assert_eq!(expected, "USD");
+                assert_eq!(found, "CAD");
+            }
+            other => panic!("unexpected error {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_formatting_uses_currency_exponent_and_locale() {
+        assert_eq!(Money::new(123450, "USD").to_string(), "1234.50 USD");
+        assert_eq!(Money::new(-5, "USD").to_decimal_string(), "-0.05");
+        assert_eq!(Money::new(1500, "JPY").to_decimal_string(), "1500");
+        assert_eq!(Money::new(1500, "KWD").to_decimal_string(), "1.500");
+
+        assert_eq!(Money::new(123450, "USD").format("en-US"), "$1,234.50");
+        assert_eq!(Money::new(123450, "EUR").format("de-DE"), "1.234,50\u{a0}€");
+        assert_eq!(Money::new(123450, "EUR").format("fr_FR"), "1\u{a0}234,50\u{a0}€");
+        assert_eq!(Money::new(1099, "BRL").format("pt-BR"), "R$\u{a0}10,99");
+        assert_eq!(Money::new(150000, "JPY").format("ja-JP"), "¥150,000");
+        assert_eq!(Money::new(1000, "CHF").format("en-US"), "CHF\u{a0}10.00");
+        assert_eq!(Money::new(-1099, "GBP").format("en-GB"), "-£10.99");
+    }
+
+    #[test]
+    fn test_eats_money_shape() {
+        let charges: Charges = serde_json::from_str(r#"{
+            "total": { "amount": 1099, "currency_code": "USD", "formatted_amount": "$10.99" },
+            "tip": { "amount": 200, "currency_code": "USD" }
+        }"#).unwrap();
+
+        let total = charges.total.unwrap();
+        assert_eq!(total, Money::new(1099, "USD"));
+        assert_eq!(total.checked_add(&charges.tip.unwrap()).unwrap().amount(), 1299);
+
+        let json = serde_json::to_value(&total).unwrap();
+        assert_eq!(json["amount"], 1099);
+        assert_eq!(json["currency_code"], "USD");
+        assert_eq!(json["formatted_amount"], "$10.99");
+
+        let charges: Charges = serde_json::from_str(r#"{ "total": null }"#).unwrap();
+        assert!(charges.total.is_none());
+        // a charge without an amount is left out, the rest of the order still parses
+        let charges: Charges = serde_json::from_str(r#"{ "total": { "currency_code": "USD" }, "tip": { "amount": 200 } }"#).unwrap();
+        assert!(charges.total.is_none());
+        assert_eq!(charges.tip, Some(uber_api::models::order::get::Money::new(200, "USD")));
+        assert!(serde_json::from_str::<Money>(r#"{ "currency_code": "USD" }"#).is_err());
+    }
+
+    #[test]
+    fn test_direct_amounts_use_delivery_currency() {
+        let delivery: GetDeliveryResponse = serde_json::from_str(r#"{
+            "fee": 799,
+            "tip": 150,
+            "manifest": { "description": "Bag", "total_value": 1099 },
+            "currency": "cad"
+        }"#).unwrap();
+        assert_eq!(delivery.fee, Some(Money::new(799, "CAD")));
+        assert_eq!(delivery.tip, Some(Money::new(150, "CAD")));
+        assert_eq!(delivery.manifest.unwrap().total_value, Some(Money::new(1099, "CAD")));
+
+        let delivery: GetDeliveryResponse = serde_json::from_str(r#"{"fee": 799}"#).unwrap();
+        assert_eq!(delivery.fee, Some(Money::new(799, "USD")));
+        assert_eq!(delivery.tip, None);
+
+        // currency is read after fee here, the fee is still in it
+        let quote: CreateQuoteResponse = serde_json::from_str(r#"{"fee": 1250, "currency": "eur"}"#).unwrap();
+        assert_eq!(quote.fee, Some(Money::new(1250, "EUR")));
+    }
+
+    #[test]
+    fn test_request_amounts_are_sent_as_minor_units() {
+        let request = CreateDeliveryRequest::builder()
+            .with_pickup("Store", "20 W 34th St, New York, NY 10001", "+15555555555")
+            .with_dropoff("Customer", "285 Fulton St, New York, NY 10007", "+15555555556")
+            .with_manifest_item(ManifestItem { price: Some(Money::new(549, "USD")), ..ManifestItem::new("Bag", 1, "small") })
+            .with_manifest_total_value(Money::new(1099, "USD"))
+            .with_tip(Money::new(300, "USD"))
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["manifest_total_value"], 1099);
+        assert_eq!(json["tip"], 300);
+        assert_eq!(json["manifest_items"][0]["price"], 549);
+
+        let update = UpdateDeliveryRequest { tip_by_customer: Some(Money::new(500, "USD")), ..Default::default() };
+        assert_eq!(serde_json::to_value(&update).unwrap()["tip_by_customer"], 500);
+    }
+
+    #[test]
+    fn test_builder_rejects_negative_and_mixed_currency_amounts() {
+        let builder = || CreateDeliveryRequest::builder()
+            .with_pickup("Store", "20 W 34th St, New York, NY 10001", "+15555555555")
+            .with_dropoff("Customer", "285 Fulton St, New York, NY 10007", "+15555555556")
+            .with_manifest_item(ManifestItem::new("Bag", 1, "small"));
+
+        let err = builder().with_tip(Money::new(-1, "USD")).build().unwrap_err();
+        assert!(matches!(err, UberError::BadInput(message) if message.starts_with("tip:")));
+
+        let err = builder()
+            .with_manifest_item(ManifestItem { price: Some(Money::new(-1, "USD")), ..ManifestItem::new("Box", 1, "small") })
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, UberError::BadInput(message) if message.starts_with("manifest_items[1].price:")));
+
+        let err = builder()
+            .with_manifest_total_value(Money::new(1099, "USD"))
+            .with_tip(Money::new(300, "EUR"))
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, UberError::BadInput(message) if message.starts_with("tip:") && message.contains("EUR")));
+    }
+}
@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod pod_retrieval_tests {
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD;
+    use uber_api::{ImageFormat, PODRetrievalRequest, PODRetrievalResponse, PodType, PodWaypoint, ProofOfDelivery, UberError};
+
+    const PNG_BYTES: [u8; 13] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n', 0, 0, 0, 13, b'I'];
+    const JPEG_BYTES: [u8; 6] = [0xff, 0xd8, 0xff, 0xe0, 0, 16];
+
+    #[test]
+    fn test_request_uses_ubers_type_field() {
+        let request = PODRetrievalRequest::new(PodWaypoint::Dropoff, PodType::Signature);
+        let json = serde_json::to_value(request).unwrap();
+
+        assert_eq!(json, serde_json::json!({ "waypoint": "dropoff", "type": "signature" }));
+    }
+
+    #[test]
+    fn test_decode_detects_png_and_jpeg() {
+        let response = PODRetrievalResponse { document: Some(STANDARD.encode(PNG_BYTES)) };
+        let proof = response.decode().unwrap();
+        assert_eq!(proof.format(), ImageFormat::Png);
+        assert_eq!(proof.format().extension(), "png");
+        assert_eq!(proof.bytes(), &PNG_BYTES);
+
+        let proof = ProofOfDelivery::from_base64(&STANDARD.encode(JPEG_BYTES)).unwrap();
+        assert_eq!(proof.format(), ImageFormat::Jpeg);
+        assert_eq!(proof.format().mime_type(), "image/jpeg");
+
+        let proof = ProofOfDelivery::from_base64(&STANDARD.encode(b"not an image")).unwrap();
+        assert_eq!(proof.format(), ImageFormat::Unknown);
+    }
+
+    #[test]
+    fn test_decode_tolerates_data_url_line_breaks_and_missing_padding() {
+        let encoded = STANDARD.encode(PNG_BYTES);
+        let wrapped = format!("data:image/png;base64,{}\n{}", &encoded[..8], encoded[8..].trim_end_matches('='));
+
+        let proof = ProofOfDelivery::from_base64(&wrapped).unwrap();
+        assert_eq!(proof.into_bytes(), PNG_BYTES.to_vec());
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        let err = PODRetrievalResponse { document: None }.decode().unwrap_err();
+        assert!(matches!(err, UberError::NotFound(_)));
+
+        let err = ProofOfDelivery::from_base64("not base64!").unwrap_err();
+        assert!(matches!(err, UberError::Base64Error(_)));
+    }
+
+    #[tokio::test]
+    async fn test_save_and_write_to() {
+        let proof = ProofOfDelivery::from_bytes(JPEG_BYTES.to_vec());
+
+        let path = std::env::temp_dir().join(format!("uber_api_pod_{}.{}", std::process::id(), proof.format().extension()));
+        proof.save(&path).await.unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), JPEG_BYTES.to_vec());
+        std::fs::remove_file(&path).unwrap();
+
+        let mut buffer: Vec<u8> = Vec::new();
+        proof.write_to(&mut buffer).await.unwrap();
+        assert_eq!(buffer, JPEG_BYTES.to_vec());
+    }
+}
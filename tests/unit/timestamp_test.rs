@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod timestamp_tests {
+    use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+    use uber_api::models::general::Timestamp;
+
+    #[test]
+    fn test_serializes_as_utc_rfc3339() {
+        let timestamp = Timestamp::from(Utc.with_ymd_and_hms(2023, 2, 9, 9, 29, 27).unwrap());
+        assert_eq!(serde_json::to_string(&timestamp).unwrap(), "\"2023-02-09T09:29:27Z\"");
+    }
+
+    #[test]
+    fn test_offsets_are_converted_to_the_same_instant() {
+        let offset = FixedOffset::west_opt(5 * 3600).unwrap();
+        let timestamp = Timestamp::from(offset.with_ymd_and_hms(2023, 2, 9, 4, 29, 27).unwrap());
+
+        assert_eq!(timestamp.to_string(), "2023-02-09T09:29:27Z");
+        assert_eq!(DateTime::<FixedOffset>::from(timestamp), offset.with_ymd_and_hms(2023, 2, 9, 4, 29, 27).unwrap());
+    }
+
+    #[test]
+    fn test_deserializes_fractional_seconds_and_offsets() {
+        let timestamp: Timestamp = serde_json::from_str("\"2019-10-12T07:20:50.52Z\"").unwrap();
+        assert_eq!(timestamp.to_string(), "2019-10-12T07:20:50.520Z");
+
+        let timestamp: Timestamp = serde_json::from_str("\"2023-02-09T11:29:27+02:00\"").unwrap();
+        assert_eq!(DateTime::<Utc>::from(timestamp), Utc.with_ymd_and_hms(2023, 2, 9, 9, 29, 27).unwrap());
+    }
+
+    #[test]
+    fn test_malformed_input_is_a_serde_error() {
+        let err = serde_json::from_str::<Timestamp>("\"yesterday\"").unwrap_err();
+        assert!(err.to_string().contains("invalid RFC 3339 timestamp 'yesterday'"));
+    }
+
+    #[test]
+    fn test_store_local_time() {
+        let timestamp: Timestamp = "2023-07-01T18:00:00Z".parse().unwrap();
+        let store_time = timestamp.to_timezone(&FixedOffset::east_opt(10 * 3600).unwrap());
+
+        assert_eq!(store_time.to_rfc3339(), "2023-07-02T04:00:00+10:00");
+    }
+}
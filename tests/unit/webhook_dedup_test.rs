@@ -0,0 +1,142 @@
+#[cfg(test)]
+mod webhook_dedup_tests {
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::Duration;
+
+    use uber_api::UberError;
+    use uber_api::models::general::Timestamp;
+    use uber_api::models::webhooks::{parse_webhook, Deduplicate, DeliveryStatus, MemoryEventStore, SeenEventStore, WebhookHandler};
+
+    #[derive(Default)]
+    struct Recorder {
+        statuses: Mutex<Vec<String>>,
+        fail: AtomicBool,
+        slow: AtomicBool,
+    }
+
+    impl WebhookHandler for Recorder {
+        async fn on_delivery_status(&self, event: DeliveryStatus) -> Result<(), UberError> {
+            if self.fail.load(Ordering::SeqCst) {
+                return Err(UberError::InternalServerError("handler failed".to_string()));
+            }
+            self.statuses.lock().unwrap().push(event.status.to_string());
+            if self.slow.load(Ordering::SeqCst) {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+            }
+            Ok(())
+        }
+    }
+
+    fn status(id: &str, status: &str, created: &str) -> Vec<u8> {
+        serde_json::to_vec(&serde_json::json!({
+            "id": id,
+            "kind": "event.delivery_status",
+            "status": status,
+            "delivery_id": "del_1",
+            "created": created,
+        })).unwrap()
+    }
+
+    async fn deliver<H: WebhookHandler>(handler: &H, body: &[u8]) -> Result<(), UberError> {
+        handler.handle(parse_webhook(body).unwrap()).await
+    }
+
+    fn statuses(handler: &Deduplicate<Recorder>) -> Vec<String> {
+        handler.handler().statuses.lock().unwrap().clone()
+    }
+
+    #[tokio::test]
+    async fn test_repeated_event_ids_are_dropped() {
+        let handler = Deduplicate::new(Recorder::default());
+        let body = status("evt_1", "pickup", "2024-03-01T12:00:00Z");
+
+        deliver(&handler, &body).await.unwrap();
+        deliver(&handler, &body).await.unwrap();
+
+        assert_eq!(statuses(&handler), vec!["pickup"]);
+    }
+
+    #[tokio::test]
+    async fn test_late_status_never_overwrites_a_newer_one() {
+        let handler = Deduplicate::new(Recorder::default());
+
+        deliver(&handler, &status("evt_2", "delivered", "2024-03-01T12:30:00Z")).await.unwrap();
+        deliver(&handler, &status("evt_1", "pickup", "2024-03-01T12:00:00Z")).await.unwrap();
+
+        assert_eq!(statuses(&handler), vec!["delivered"]);
+    }
+
+    #[tokio::test]
+    async fn test_late_status_arriving_while_a_newer_one_is_handled_is_dropped() {
+        let handler = Deduplicate::new(Recorder::default());
+        handler.handler().slow.store(true, Ordering::SeqCst);
+
+        let delivered = status("evt_2", "delivered", "2024-03-01T12:30:00Z");
+        let pickup = status("evt_1", "pickup", "2024-03-01T12:00:00Z");
+        let (first, second) = tokio::join!(deliver(&handler, &delivered), deliver(&handler, &pickup));
+        first.unwrap();
+        second.unwrap();
+
+        assert_eq!(statuses(&handler), vec!["delivered"]);
+    }
+
+    #[tokio::test]
+    async fn test_failed_event_is_handled_on_retry() {
+        let handler = Deduplicate::new(Recorder::default());
+        let body = status("evt_1", "pickup", "2024-03-01T12:00:00Z");
+
+        handler.handler().fail.store(true, Ordering::SeqCst);
+        assert!(deliver(&handler, &body).await.is_err());
+        // a failed event doesn't count as applied either
+        assert_eq!(handler.store().last_applied("del_1").await.unwrap(), None);
+
+        handler.handler().fail.store(false, Ordering::SeqCst);
+        deliver(&handler, &body).await.unwrap();
+
+        assert_eq!(statuses(&handler), vec!["pickup"]);
+    }
+
+    #[tokio::test]
+    async fn test_events_without_id_or_created_are_applied() {
+        let handler = Deduplicate::new(Recorder::default());
+        let body = br#"{ "kind": "event.delivery_status", "status": "pickup", "delivery_id": "del_1" }"#;
+
+        deliver(&handler, body).await.unwrap();
+        deliver(&handler, body).await.unwrap();
+
+        assert_eq!(statuses(&handler), vec!["pickup", "pickup"]);
+    }
+
+    #[tokio::test]
+    async fn test_released_delivery_claim_goes_back_to_the_previous_one() {
+        let store = MemoryEventStore::default();
+        let pickup: Timestamp = "2024-03-01T12:00:00Z".parse().unwrap();
+        let delivered: Timestamp = "2024-03-01T12:30:00Z".parse().unwrap();
+
+        assert!(store.claim_applied("del_1", pickup).await.unwrap());
+        assert!(store.claim_applied("del_1", delivered).await.unwrap());
+        assert!(!store.claim_applied("del_1", pickup).await.unwrap());
+
+        store.release_applied("del_1", delivered).await.unwrap();
+        assert_eq!(store.last_applied("del_1").await.unwrap(), Some(pickup));
+        // releasing a claim that was replaced leaves the later one
+        assert!(store.claim_applied("del_1", delivered).await.unwrap());
+        store.release_applied("del_1", pickup).await.unwrap();
+        assert_eq!(store.last_applied("del_1").await.unwrap(), Some(delivered));
+    }
+
+    #[tokio::test]
+    async fn test_memory_store_forgets_least_recently_used() {
+        let store = MemoryEventStore::new(2);
+
+        assert!(store.claim("evt_1").await.unwrap());
+        assert!(store.claim("evt_2").await.unwrap());
+        assert!(!store.claim("evt_1").await.unwrap());
+        assert!(store.claim("evt_3").await.unwrap());
+
+        // evt_2 was the least recently used
+        assert!(store.claim("evt_2").await.unwrap());
+        assert!(!store.claim("evt_3").await.unwrap());
+    }
+}
@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod webhook_event_tests {
+    use std::sync::Mutex;
+
+    use uber_api::{DeliveryState, UberError};
+    use uber_api::models::webhooks::{parse_webhook, CourierUpdate, DeliveryStatus, WebhookEvent, WebhookHandler};
+
+    const DELIVERY_STATUS: &[u8] = br#"{
+        "id": "evt_1",
+        "kind": "event.delivery_status",
+        "status": "pickup",
+        "delivery_id": "del_1",
+        "created": "2024-03-01T12:00:00Z"
+    }"#;
+    const DELIVERY_RETURN: &[u8] = br#"{ "kind": "event.delivery_return", "status": "returned", "delivery_id": "del_1" }"#;
+    const COURIER_UPDATE: &[u8] = br#"{ "kind": "event.courier_update", "location": { "lat": 40.7, "lng": -73.9 }, "delivery_id": "del_1" }"#;
+    const REFUND: &[u8] = br#"{ "kind": "dapi.refund_requested", "delivery_id": "del_2", "amount": 500 }"#;
+
+    #[derive(Default)]
+    struct Recorder {
+        calls: Mutex<Vec<String>>,
+    }
+
+    impl Recorder {
+        fn record(&self, call: String) {
+            self.calls.lock().unwrap().push(call);
+        }
+    }
+
+    impl WebhookHandler for Recorder {
+        async fn on_delivery_status(&self, event: DeliveryStatus) -> Result<(), UberError> {
+            self.record(format!("status {}", event.status));
+            Ok(())
+        }
+
+        async fn on_courier_update(&self, event: CourierUpdate) -> Result<(), UberError> {
+            self.record(format!("courier {}", event.location.lat));
+            Ok(())
+        }
+
+        async fn on_unknown(&self, kind: Option<String>, _payload: serde_json::Value) -> Result<(), UberError> {
+            self.record(format!("unknown {}", kind.unwrap_or_default()));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_parse_by_kind() {
+        let event = parse_webhook(DELIVERY_STATUS).unwrap();
+        assert!(matches!(&event, WebhookEvent::DeliveryStatus(status) if status.status == DeliveryState::Pickup));
+        assert_eq!(event.kind(), "event.delivery_status");
+        assert_eq!(event.delivery_id(), Some("del_1"));
+
+        assert!(matches!(parse_webhook(DELIVERY_RETURN).unwrap(), WebhookEvent::DeliveryReturn(_)));
+        assert!(matches!(parse_webhook(COURIER_UPDATE).unwrap(), WebhookEvent::CourierUpdate(_)));
+    }
+
+    #[test]
+    fn test_unknown_kind_keeps_payload() {
+        let event = parse_webhook(REFUND).unwrap();
+
+        assert_eq!(event.kind(), "dapi.refund_requested");
+        assert_eq!(event.delivery_id(), Some("del_2"));
+        match event {
+            WebhookEvent::Unknown { payload, .. } => assert_eq!(payload["amount"], 500),
+            other => panic!("unexpected event {:?}", other),
+        }
+
+        assert!(matches!(parse_webhook(b"{}").unwrap(), WebhookEvent::Unknown { kind: None, .. }));
+    }
+
+    #[test]
+    fn test_known_kind_with_bad_payload_is_an_error() {
+        let err = parse_webhook(br#"{ "kind": "event.courier_update" }"#).unwrap_err();
+        assert!(matches!(err, UberError::JsonError(_)));
+
+        assert!(parse_webhook(b"not json").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_handler_dispatch() {
+        let recorder = Recorder::default();
+        for body in [DELIVERY_STATUS, DELIVERY_RETURN, COURIER_UPDATE, REFUND] {
+            recorder.handle(parse_webhook(body).unwrap()).await.unwrap();
+        }
+
+        let calls = recorder.calls.into_inner().unwrap();
+        // returns go to on_delivery_status unless overridden
+        assert_eq!(calls, vec!["status pickup", "status returned", "courier 40.7", "unknown dapi.refund_requested"]);
+    }
+}
@@ -0,0 +1,136 @@
+#[cfg(test)]
+mod webhook_journal_tests {
+    use std::collections::BTreeMap;
+    use std::path::PathBuf;
+    use std::sync::Mutex;
+
+    use reqwest::header::{HeaderMap, HeaderValue};
+    use uber_api::UberError;
+    use uber_api::models::general::Timestamp;
+    use uber_api::models::webhooks::{read_journal, replay_journal, sign_webhook, verify_webhook, CourierUpdate, DeliveryStatus, JournalEntry, ReplayFilter, WebhookHandler, WebhookJournal};
+
+    #[derive(Default)]
+    struct Recorder {
+        calls: Mutex<Vec<String>>,
+    }
+
+    impl WebhookHandler for Recorder {
+        async fn on_delivery_status(&self, event: DeliveryStatus) -> Result<(), UberError> {
+            if event.delivery_id.as_deref() == Some("del_broken") {
+                return Err(UberError::InternalServerError("handler bug".to_string()));
+            }
+            self.calls.lock().unwrap().push(format!("{} {}", event.delivery_id.unwrap_or_default(), event.status));
+            Ok(())
+        }
+
+        async fn on_courier_update(&self, event: CourierUpdate) -> Result<(), UberError> {
+            self.calls.lock().unwrap().push(format!("{} courier", event.delivery_id.unwrap_or_default()));
+            Ok(())
+        }
+    }
+
+    fn journal_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("uber_api_journal_{}_{}.jsonl", name, std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    fn entry(received: &str, body: serde_json::Value) -> JournalEntry {
+        JournalEntry {
+            received: received.parse().unwrap(),
+            headers: BTreeMap::new(),
+            body: body.to_string().into_bytes(),
+        }
+    }
+
+    async fn write_journal(path: &PathBuf) {
+        let journal = WebhookJournal::open(path).await.unwrap();
+        journal.append(&entry("2024-03-01T12:00:00Z", serde_json::json!({ "kind": "event.delivery_status", "status": "pickup", "delivery_id": "del_1" }))).await.unwrap();
+        journal.append(&entry("2024-03-01T12:05:00Z", serde_json::json!({ "kind": "event.courier_update", "location": { "lat": 40.7, "lng": -73.9 }, "delivery_id": "del_1" }))).await.unwrap();
+        journal.append(&entry("2024-03-01T12:10:00Z", serde_json::json!({ "kind": "event.delivery_status", "status": "pickup", "delivery_id": "del_2" }))).await.unwrap();
+        journal.append(&entry("2024-03-01T12:20:00Z", serde_json::json!({ "kind": "event.delivery_status", "status": "delivered", "delivery_id": "del_1" }))).await.unwrap();
+        journal.append(&entry("2024-03-01T12:30:00Z", serde_json::json!({ "kind": "event.delivery_status", "status": "delivered", "delivery_id": "del_broken" }))).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_record_keeps_body_and_headers_verifiable() {
+        let path = journal_path("record");
+        let body = br#"{"kind":"event.delivery_status","status":"pickup","delivery_id":"del_1"}"#;
+        let mut headers = HeaderMap::new();
+        headers.insert("X-Uber-Signature", HeaderValue::from_str(&sign_webhook("key", body)).unwrap());
+        headers.insert("Content-Type", HeaderValue::from_static("application/json"));
+        headers.insert("Authorization", HeaderValue::from_static("Bearer proxy_token"));
+        headers.insert("Cookie", HeaderValue::from_static("session=abc"));
+
+        let before = Timestamp::now();
+        WebhookJournal::open(&path).await.unwrap().record(&headers, body).await.unwrap();
+        // reopening appends
+        WebhookJournal::open(&path).await.unwrap().record(&headers, body).await.unwrap();
+
+        let entries = read_journal(&path, &ReplayFilter::new()).await.unwrap();
+        assert_eq!(entries.len(), 2);
+        let (line, entry) = &entries[1];
+        assert_eq!(*line, 2);
+        assert!(entry.received >= before);
+        assert_eq!(entry.body, body);
+        assert!(verify_webhook("key", &entry.header_map(), &entry.body).is_ok());
+        // only the headers needed to verify and parse the body are journaled
+        assert_eq!(entry.headers.keys().collect::<Vec<_>>(), vec!["content-type", "x-uber-signature"]);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_entry_keeps_non_utf8_bodies_byte_for_byte() {
+        let body: &[u8] = b"{\"kind\":\"event.courier_update\",\"note\":\"\xff\xfe\"}";
+        let mut headers = HeaderMap::new();
+        headers.insert("X-Uber-Signature", HeaderValue::from_str(&sign_webhook("key", body)).unwrap());
+
+        let line = serde_json::to_string(&JournalEntry::new(&headers, body)).unwrap();
+        let entry: JournalEntry = serde_json::from_str(&line).unwrap();
+        assert_eq!(entry.body, body);
+        assert!(entry.verify("key").is_ok());
+        assert!(entry.verify("other_key").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_replay_filters() {
+        let path = journal_path("filters");
+        write_journal(&path).await;
+
+        let recorder = Recorder::default();
+        let filter = ReplayFilter::new().with_delivery_id("del_1").with_kind("event.delivery_status");
+        let report = replay_journal(&path, &filter, &recorder).await.unwrap();
+        assert_eq!(report.replayed, 2);
+        assert_eq!(report.skipped, 3);
+        assert_eq!(recorder.calls.into_inner().unwrap(), vec!["del_1 pickup", "del_1 delivered"]);
+
+        let filter = ReplayFilter::new()
+            .with_from("2024-03-01T12:05:00Z".parse().unwrap())
+            .with_until("2024-03-01T12:20:00Z".parse().unwrap());
+        let lines: Vec<usize> = read_journal(&path, &filter).await.unwrap().into_iter().map(|(line, _)| line).collect();
+        assert_eq!(lines, vec![2, 3]);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_replay_goes_on_past_failures() {
+        let path = journal_path("failures");
+        write_journal(&path).await;
+        let mut contents = std::fs::read_to_string(&path).unwrap();
+        contents.push_str("{\"received\":\"2024-03-01T12:40:00Z\",\"head");
+        std::fs::write(&path, contents).unwrap();
+
+        let recorder = Recorder::default();
+        let report = replay_journal(&path, &ReplayFilter::new(), &recorder).await.unwrap();
+
+        assert_eq!(report.replayed, 4);
+        let failed_lines: Vec<usize> = report.failures.iter().map(|failure| failure.line).collect();
+        // the cut short line is reported while reading, before any event is handled
+        assert_eq!(failed_lines, vec![6, 5]);
+        assert_eq!(recorder.calls.into_inner().unwrap().len(), 4);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
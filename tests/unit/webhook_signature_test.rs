@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod webhook_signature_tests {
+    use reqwest::header::{HeaderMap, HeaderValue};
+    use uber_api::UberError;
+    use uber_api::models::webhooks::{sign_webhook, verify_webhook};
+
+    const BODY: &[u8] = b"The quick brown fox jumps over the lazy dog";
+    // HMAC-SHA256 of BODY keyed with "key"
+    const SIGNATURE: &str = "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8";
+
+    fn headers(name: &'static str, value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(name, HeaderValue::from_str(value).unwrap());
+        headers
+    }
+
+    #[test]
+    fn test_sign_webhook_is_hex_hmac_sha256() {
+        assert_eq!(sign_webhook("key", BODY), SIGNATURE);
+    }
+
+    #[test]
+    fn test_accepts_either_signature_header() {
+        assert!(verify_webhook("key", &headers("X-Uber-Signature", SIGNATURE), BODY).is_ok());
+        assert!(verify_webhook("key", &headers("X-Postmates-Signature", SIGNATURE), BODY).is_ok());
+        assert!(verify_webhook("key", &headers("X-Uber-Signature", &SIGNATURE.to_uppercase()), BODY).is_ok());
+    }
+
+    #[test]
+    fn test_missing_signature() {
+        let err = verify_webhook("key", &HeaderMap::new(), BODY).unwrap_err();
+        assert!(matches!(err, UberError::MissingSignature(_)));
+        assert_eq!(err.get_code(), "unauthorized");
+
+        let err = verify_webhook("key", &headers("X-Uber-Signature", " "), BODY).unwrap_err();
+        assert!(matches!(err, UberError::MissingSignature(_)));
+    }
+
+    #[test]
+    fn test_mismatched_signature() {
+        let err = verify_webhook("other_key", &headers("X-Uber-Signature", SIGNATURE), BODY).unwrap_err();
+        assert!(matches!(err, UberError::InvalidSignature(_)));
+
+        let err = verify_webhook("key", &headers("X-Uber-Signature", SIGNATURE), b"tampered body").unwrap_err();
+        assert!(matches!(err, UberError::InvalidSignature(_)));
+
+        let err = verify_webhook("key", &headers("X-Uber-Signature", "not-hex"), BODY).unwrap_err();
+        assert!(matches!(err, UberError::InvalidSignature(_)));
+    }
+}